@@ -1,27 +1,16 @@
 const lAudioContext = (typeof AudioContext !== 'undefined' ? AudioContext : (typeof webkitAudioContext !== 'undefined' ? webkitAudioContext : undefined));
 let wasm;
 
-const heap = new Array(128).fill(undefined);
-
-heap.push(undefined, null, true, false);
-
-function getObject(idx) { return heap[idx]; }
-
-let heap_next = heap.length;
-
-function addHeapObject(obj) {
-    if (heap_next === heap.length) heap.push(heap.length + 1);
-    const idx = heap_next;
-    heap_next = heap[idx];
-
-    heap[idx] = obj;
-    return idx;
-}
-
 function isLikeNone(x) {
     return x === undefined || x === null;
 }
 
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_export_1.set(idx, obj);
+    return idx;
+}
+
 const cachedTextDecoder = (typeof TextDecoder !== 'undefined' ? new TextDecoder('utf-8', { ignoreBOM: true, fatal: true }) : { decode: () => { throw Error('TextDecoder not available') } } );
 
 if (typeof TextDecoder !== 'undefined') { cachedTextDecoder.decode(); };
@@ -44,7 +33,8 @@ function handleError(f, args) {
     try {
         return f.apply(this, args);
     } catch (e) {
-        wasm.__wbindgen_exn_store(addHeapObject(e));
+        const idx = addToExternrefTable0(e);
+        wasm.__wbindgen_exn_store(idx);
     }
 }
 
@@ -155,22 +145,10 @@ function getArrayF32FromWasm0(ptr, len) {
     return getFloat32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
 }
 
-function dropObject(idx) {
-    if (idx < 132) return;
-    heap[idx] = heap_next;
-    heap_next = idx;
-}
-
-function takeObject(idx) {
-    const ret = getObject(idx);
-    dropObject(idx);
-    return ret;
-}
-
 const CLOSURE_DTORS = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(state => {
-    wasm.__wbindgen_export_4.get(state.dtor)(state.a, state.b)
+    wasm.__wbindgen_export_6.get(state.dtor)(state.a, state.b)
 });
 
 function makeMutClosure(arg0, arg1, dtor, f) {
@@ -186,7 +164,7 @@ function makeMutClosure(arg0, arg1, dtor, f) {
             return f(a, state.b, ...args);
         } finally {
             if (--state.cnt === 0) {
-                wasm.__wbindgen_export_4.get(state.dtor)(a, state.b);
+                wasm.__wbindgen_export_6.get(state.dtor)(a, state.b);
                 CLOSURE_DTORS.unregister(state);
             } else {
                 state.a = a;
@@ -263,24 +241,161 @@ function debugString(val) {
     return className;
 }
 
-export function settings_clicked() {
-    wasm.settings_clicked();
+function takeFromExternrefTable0(idx) {
+    const value = wasm.__wbindgen_export_1.get(idx);
+    wasm.__externref_table_dealloc(idx);
+    return value;
+}
+/**
+ * Sets one field of the settings by its saved name, e.g.
+ * `set_setting("volume", 0.3)` or `set_setting("sound_enabled", false)`.
+ * Numbers must be within the range of the settings menu's slider, and
+ * `visual_effects` ("Low", "Normal" or "High") applies that preset.
+ * @param {string} name
+ * @param {any} value
+ */
+export function set_setting(name, value) {
+    const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.set_setting(ptr0, len0, value);
+    if (ret[1]) {
+        throw takeFromExternrefTable0(ret[0]);
+    }
+}
+
+/**
+ * Clears the container and starts a fresh game.
+ */
+export function restart() {
+    wasm.restart();
+}
+
+/**
+ * The latest official Daily result as text ready to paste, with the date,
+ * score and how far up the chain it got. Undefined until one has finished.
+ * @returns {string | undefined}
+ */
+export function get_daily_share_text() {
+    const ret = wasm.get_daily_share_text();
+    let v1;
+    if (ret[0] !== 0) {
+        v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+}
+
+/**
+ * The current score.
+ * @returns {number}
+ */
+export function get_score() {
+    const ret = wasm.get_score();
+    return ret >>> 0;
+}
+
+/**
+ * The game state: "MainMenu", "Playing", "Paused", "GameOver", "Win" or "Settings".
+ * @returns {string}
+ */
+export function get_state() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+        const ret = wasm.get_state();
+        deferred1_0 = ret[0];
+        deferred1_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+}
+
+/**
+ * The ball that drops next as `{ index, name, sprite }`, or null before the
+ * game has started and once a puzzle has no balls left.
+ * @returns {any}
+ */
+export function get_next_ball() {
+    const ret = wasm.get_next_ball();
+    return ret;
+}
+
+/**
+ * Calls `callback({ won, mode, score })` when a game ends.
+ * @param {Function} callback
+ */
+export function on_game_over(callback) {
+    wasm.on_game_over(callback);
+}
+
+/**
+ * The balls after the next one, soonest first, as many as the HUD shows.
+ * @returns {any}
+ */
+export function get_upcoming_balls() {
+    const ret = wasm.get_upcoming_balls();
+    return ret;
+}
+
+/**
+ * Calls `callback({ from, into, points, combo, x, y })` after every merge.
+ * @param {Function} callback
+ */
+export function on_merge(callback) {
+    wasm.on_merge(callback);
+}
+
+/**
+ * Pauses a game in progress until `resume()`.
+ */
+export function pause() {
+    wasm.pause();
+}
+
+/**
+ * The best score, including previous sessions.
+ * @returns {number}
+ */
+export function get_high_score() {
+    const ret = wasm.get_high_score();
+    return ret >>> 0;
+}
+
+export function resume() {
+    wasm.resume();
 }
 
 export function wasm_main() {
     wasm.wasm_main();
 }
 
-function __wbg_adapter_34(arg0, arg1) {
-    wasm._dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h9302db5f9e8a4b2e(arg0, arg1);
+export function settings_clicked() {
+    wasm.settings_clicked();
+}
+
+function __wbg_adapter_36(arg0, arg1, arg2) {
+    wasm.closure12012_externref_shim(arg0, arg1, arg2);
+}
+
+function __wbg_adapter_39(arg0, arg1) {
+    wasm._dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h50414b3dc8a4e19b(arg0, arg1);
+}
+
+function __wbg_adapter_54(arg0, arg1, arg2, arg3) {
+    wasm.closure12017_externref_shim(arg0, arg1, arg2, arg3);
+}
+
+function __wbg_adapter_57(arg0, arg1) {
+    wasm._dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h059124e6db927739(arg0, arg1);
 }
 
-function __wbg_adapter_37(arg0, arg1, arg2) {
-    wasm._dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h040d184f00a7eb90(arg0, arg1, addHeapObject(arg2));
+function __wbg_adapter_60(arg0, arg1) {
+    wasm._dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h3fce110f8bd12cde(arg0, arg1);
 }
 
-function __wbg_adapter_48(arg0, arg1, arg2, arg3) {
-    wasm._dyn_core__ops__function__FnMut__A_B___Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h0b351159393bee00(arg0, arg1, addHeapObject(arg2), addHeapObject(arg3));
+function __wbg_adapter_63(arg0, arg1, arg2) {
+    wasm.closure91746_externref_shim(arg0, arg1, arg2);
 }
 
 const __wbindgen_enum_GamepadMappingType = ["", "standard"];
@@ -324,585 +439,585 @@ function __wbg_get_imports() {
     const imports = {};
     imports.wbg = {};
     imports.wbg.__wbg_Window_96e58b3552ce6bb1 = function(arg0) {
-        const ret = getObject(arg0).Window;
-        return addHeapObject(ret);
+        const ret = arg0.Window;
+        return ret;
     };
     imports.wbg.__wbg_Window_f4849ee751d39d85 = function(arg0) {
-        const ret = getObject(arg0).Window;
-        return addHeapObject(ret);
+        const ret = arg0.Window;
+        return ret;
     };
     imports.wbg.__wbg_WorkerGlobalScope_0f54bb2c92552bbd = function(arg0) {
-        const ret = getObject(arg0).WorkerGlobalScope;
-        return addHeapObject(ret);
+        const ret = arg0.WorkerGlobalScope;
+        return ret;
     };
     imports.wbg.__wbg_abort_19de2f828ee0874a = function(arg0) {
-        getObject(arg0).abort();
+        arg0.abort();
     };
     imports.wbg.__wbg_activeElement_d1a1f2b334adf636 = function(arg0) {
-        const ret = getObject(arg0).activeElement;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.activeElement;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_activeTexture_24b42b03041f3428 = function(arg0, arg1) {
-        getObject(arg0).activeTexture(arg1 >>> 0);
+        arg0.activeTexture(arg1 >>> 0);
     };
     imports.wbg.__wbg_activeTexture_47928613532be667 = function(arg0, arg1) {
-        getObject(arg0).activeTexture(arg1 >>> 0);
+        arg0.activeTexture(arg1 >>> 0);
     };
     imports.wbg.__wbg_addEventListener_e27053e488770e58 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        getObject(arg0).addEventListener(getStringFromWasm0(arg1, arg2), getObject(arg3));
+        arg0.addEventListener(getStringFromWasm0(arg1, arg2), arg3);
     }, arguments) };
     imports.wbg.__wbg_addListener_dca1ccc036e24997 = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).addListener(getObject(arg1));
+        arg0.addListener(arg1);
     }, arguments) };
     imports.wbg.__wbg_altKey_56dd0987e7ccbbf2 = function(arg0) {
-        const ret = getObject(arg0).altKey;
+        const ret = arg0.altKey;
         return ret;
     };
     imports.wbg.__wbg_altKey_583c79ba3f4fce1e = function(arg0) {
-        const ret = getObject(arg0).altKey;
+        const ret = arg0.altKey;
         return ret;
     };
     imports.wbg.__wbg_animate_310f1b8e5b1fdb56 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).animate(getObject(arg1), getObject(arg2));
-        return addHeapObject(ret);
+        const ret = arg0.animate(arg1, arg2);
+        return ret;
     };
     imports.wbg.__wbg_appendChild_805222aed73feea9 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).appendChild(getObject(arg1));
-        return addHeapObject(ret);
+        const ret = arg0.appendChild(arg1);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_arrayBuffer_d004045654bdb712 = function() { return handleError(function (arg0) {
-        const ret = getObject(arg0).arrayBuffer();
-        return addHeapObject(ret);
+        const ret = arg0.arrayBuffer();
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_attachShader_81000b0c4da5b206 = function(arg0, arg1, arg2) {
-        getObject(arg0).attachShader(getObject(arg1), getObject(arg2));
+        arg0.attachShader(arg1, arg2);
     };
     imports.wbg.__wbg_attachShader_ccc35921e866b2bf = function(arg0, arg1, arg2) {
-        getObject(arg0).attachShader(getObject(arg1), getObject(arg2));
+        arg0.attachShader(arg1, arg2);
     };
     imports.wbg.__wbg_axes_c6c446b7e173f6d3 = function(arg0) {
-        const ret = getObject(arg0).axes;
-        return addHeapObject(ret);
+        const ret = arg0.axes;
+        return ret;
     };
     imports.wbg.__wbg_beginQuery_4e54bbe77706fb3c = function(arg0, arg1, arg2) {
-        getObject(arg0).beginQuery(arg1 >>> 0, getObject(arg2));
+        arg0.beginQuery(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindAttribLocation_a08ae4fdf9b16e7d = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).bindAttribLocation(getObject(arg1), arg2 >>> 0, getStringFromWasm0(arg3, arg4));
+        arg0.bindAttribLocation(arg1, arg2 >>> 0, getStringFromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_bindAttribLocation_b53db53e1b134420 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).bindAttribLocation(getObject(arg1), arg2 >>> 0, getStringFromWasm0(arg3, arg4));
+        arg0.bindAttribLocation(arg1, arg2 >>> 0, getStringFromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_bindBufferRange_ce5a2827ed937112 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).bindBufferRange(arg1 >>> 0, arg2 >>> 0, getObject(arg3), arg4, arg5);
+        arg0.bindBufferRange(arg1 >>> 0, arg2 >>> 0, arg3, arg4, arg5);
     };
     imports.wbg.__wbg_bindBuffer_0fedb16582ffeee6 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindBuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindBuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindBuffer_c6e5f29d60e90c01 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindBuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindBuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindFramebuffer_87e15614ca3e9537 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindFramebuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindFramebuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindFramebuffer_a0d602bd474dcbfb = function(arg0, arg1, arg2) {
-        getObject(arg0).bindFramebuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindFramebuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindRenderbuffer_3100396894cc2d72 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindRenderbuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindRenderbuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindRenderbuffer_fd3e47dad76906ea = function(arg0, arg1, arg2) {
-        getObject(arg0).bindRenderbuffer(arg1 >>> 0, getObject(arg2));
+        arg0.bindRenderbuffer(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindSampler_7614054a94f8e3d1 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindSampler(arg1 >>> 0, getObject(arg2));
+        arg0.bindSampler(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindTexture_6478edbb238b9c73 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindTexture(arg1 >>> 0, getObject(arg2));
+        arg0.bindTexture(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindTexture_9b177c97248ed4d9 = function(arg0, arg1, arg2) {
-        getObject(arg0).bindTexture(arg1 >>> 0, getObject(arg2));
+        arg0.bindTexture(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_bindVertexArrayOES_e81fca007d08d0db = function(arg0, arg1) {
-        getObject(arg0).bindVertexArrayOES(getObject(arg1));
+        arg0.bindVertexArrayOES(arg1);
     };
     imports.wbg.__wbg_bindVertexArray_d9082254ff3bcc13 = function(arg0, arg1) {
-        getObject(arg0).bindVertexArray(getObject(arg1));
+        arg0.bindVertexArray(arg1);
     };
     imports.wbg.__wbg_blendColor_075aa544f1fe84c5 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).blendColor(arg1, arg2, arg3, arg4);
+        arg0.blendColor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_blendColor_ed292080d5966f2c = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).blendColor(arg1, arg2, arg3, arg4);
+        arg0.blendColor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_blendEquationSeparate_3dbbe20b0a9fa818 = function(arg0, arg1, arg2) {
-        getObject(arg0).blendEquationSeparate(arg1 >>> 0, arg2 >>> 0);
+        arg0.blendEquationSeparate(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_blendEquationSeparate_d360393d3b1557cd = function(arg0, arg1, arg2) {
-        getObject(arg0).blendEquationSeparate(arg1 >>> 0, arg2 >>> 0);
+        arg0.blendEquationSeparate(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_blendEquation_41f4ed79f5ddd25c = function(arg0, arg1) {
-        getObject(arg0).blendEquation(arg1 >>> 0);
+        arg0.blendEquation(arg1 >>> 0);
     };
     imports.wbg.__wbg_blendEquation_8804e10c9ced3fc4 = function(arg0, arg1) {
-        getObject(arg0).blendEquation(arg1 >>> 0);
+        arg0.blendEquation(arg1 >>> 0);
     };
     imports.wbg.__wbg_blendFuncSeparate_2cc7ac2397290a15 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).blendFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
+        arg0.blendFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
     };
     imports.wbg.__wbg_blendFuncSeparate_c6c035b0094bd58f = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).blendFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
+        arg0.blendFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
     };
     imports.wbg.__wbg_blendFunc_63aca14ff3252b59 = function(arg0, arg1, arg2) {
-        getObject(arg0).blendFunc(arg1 >>> 0, arg2 >>> 0);
+        arg0.blendFunc(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_blendFunc_8e1cfba50a4eea89 = function(arg0, arg1, arg2) {
-        getObject(arg0).blendFunc(arg1 >>> 0, arg2 >>> 0);
+        arg0.blendFunc(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_blitFramebuffer_171b9355e4e730c9 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) {
-        getObject(arg0).blitFramebuffer(arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0);
+        arg0.blitFramebuffer(arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0);
     };
     imports.wbg.__wbg_blockSize_e0006fb003814895 = function(arg0) {
-        const ret = getObject(arg0).blockSize;
+        const ret = arg0.blockSize;
         return ret;
     };
     imports.wbg.__wbg_body_83d4bc4961a422aa = function(arg0) {
-        const ret = getObject(arg0).body;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.body;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_brand_67e4f3d950154d0a = function(arg0, arg1) {
-        const ret = getObject(arg1).brand;
+        const ret = arg1.brand;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_brands_1ec54d613da9db02 = function(arg0) {
-        const ret = getObject(arg0).brands;
-        return addHeapObject(ret);
+        const ret = arg0.brands;
+        return ret;
     };
     imports.wbg.__wbg_bufferData_2a747a2c2c3b23b5 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
+        arg0.bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
     };
     imports.wbg.__wbg_bufferData_5b85d77150f6520a = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferData(arg1 >>> 0, getObject(arg2), arg3 >>> 0);
+        arg0.bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
     };
     imports.wbg.__wbg_bufferData_b03654fb80052afe = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferData(arg1 >>> 0, getObject(arg2), arg3 >>> 0);
+        arg0.bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
     };
     imports.wbg.__wbg_bufferData_c2eb7dbb2ea2ab3f = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
+        arg0.bufferData(arg1 >>> 0, arg2, arg3 >>> 0);
     };
     imports.wbg.__wbg_bufferSubData_8a7d35c05111a120 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferSubData(arg1 >>> 0, arg2, getObject(arg3));
+        arg0.bufferSubData(arg1 >>> 0, arg2, arg3);
     };
     imports.wbg.__wbg_bufferSubData_c4b11388b9eb4175 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).bufferSubData(arg1 >>> 0, arg2, getObject(arg3));
+        arg0.bufferSubData(arg1 >>> 0, arg2, arg3);
     };
     imports.wbg.__wbg_buffer_6e1d53ff183194fc = function(arg0) {
-        const ret = getObject(arg0).buffer;
-        return addHeapObject(ret);
+        const ret = arg0.buffer;
+        return ret;
     };
     imports.wbg.__wbg_button_db48f93638c59f95 = function(arg0) {
-        const ret = getObject(arg0).button;
+        const ret = arg0.button;
         return ret;
     };
     imports.wbg.__wbg_buttons_b407b60825e41ed2 = function(arg0) {
-        const ret = getObject(arg0).buttons;
-        return addHeapObject(ret);
+        const ret = arg0.buttons;
+        return ret;
     };
     imports.wbg.__wbg_buttons_b962d6dc116cd1a5 = function(arg0) {
-        const ret = getObject(arg0).buttons;
+        const ret = arg0.buttons;
         return ret;
     };
     imports.wbg.__wbg_call_0411c0c3c424db9a = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).call(getObject(arg1), getObject(arg2));
-        return addHeapObject(ret);
+        const ret = arg0.call(arg1, arg2);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_call_3114932863209ca6 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).call(getObject(arg1));
-        return addHeapObject(ret);
+        const ret = arg0.call(arg1);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_cancelAnimationFrame_f1ad512e269ea165 = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).cancelAnimationFrame(arg1);
+        arg0.cancelAnimationFrame(arg1);
     }, arguments) };
     imports.wbg.__wbg_cancelIdleCallback_bcc2dc9be9de0744 = function(arg0, arg1) {
-        getObject(arg0).cancelIdleCallback(arg1 >>> 0);
+        arg0.cancelIdleCallback(arg1 >>> 0);
     };
     imports.wbg.__wbg_cancel_3b69067dcdf7ffb7 = function(arg0) {
-        getObject(arg0).cancel();
+        arg0.cancel();
     };
     imports.wbg.__wbg_catch_9da7d002aa356f1d = function(arg0, arg1) {
-        const ret = getObject(arg0).catch(getObject(arg1));
-        return addHeapObject(ret);
+        const ret = arg0.catch(arg1);
+        return ret;
     };
     imports.wbg.__wbg_clearBufferiv_b45be02e57957dad = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).clearBufferiv(arg1 >>> 0, arg2, getArrayI32FromWasm0(arg3, arg4));
+        arg0.clearBufferiv(arg1 >>> 0, arg2, getArrayI32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_clearBufferuiv_deca83038abb23d2 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).clearBufferuiv(arg1 >>> 0, arg2, getArrayU32FromWasm0(arg3, arg4));
+        arg0.clearBufferuiv(arg1 >>> 0, arg2, getArrayU32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_clearColor_a5d4f51509d11942 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).clearColor(arg1, arg2, arg3, arg4);
+        arg0.clearColor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_clearColor_efddd2ad0120f9e5 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).clearColor(arg1, arg2, arg3, arg4);
+        arg0.clearColor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_clearDepth_14d34daa30215440 = function(arg0, arg1) {
-        getObject(arg0).clearDepth(arg1);
+        arg0.clearDepth(arg1);
     };
     imports.wbg.__wbg_clearDepth_1cdcb08d8a5afa49 = function(arg0, arg1) {
-        getObject(arg0).clearDepth(arg1);
+        arg0.clearDepth(arg1);
     };
     imports.wbg.__wbg_clearStencil_e2c8b1892d5e033d = function(arg0, arg1) {
-        getObject(arg0).clearStencil(arg1);
+        arg0.clearStencil(arg1);
     };
     imports.wbg.__wbg_clearStencil_f3138d2e338d1436 = function(arg0, arg1) {
-        getObject(arg0).clearStencil(arg1);
+        arg0.clearStencil(arg1);
     };
     imports.wbg.__wbg_clearTimeout_da4408c46e51bc7a = function(arg0, arg1) {
-        getObject(arg0).clearTimeout(arg1);
+        arg0.clearTimeout(arg1);
     };
     imports.wbg.__wbg_clear_4e68091e616c29ad = function(arg0, arg1) {
-        getObject(arg0).clear(arg1 >>> 0);
+        arg0.clear(arg1 >>> 0);
     };
     imports.wbg.__wbg_clear_af7641961d766f51 = function(arg0, arg1) {
-        getObject(arg0).clear(arg1 >>> 0);
+        arg0.clear(arg1 >>> 0);
     };
     imports.wbg.__wbg_clientWaitSync_2c4c0bd3a8d3536b = function(arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).clientWaitSync(getObject(arg1), arg2 >>> 0, arg3 >>> 0);
+        const ret = arg0.clientWaitSync(arg1, arg2 >>> 0, arg3 >>> 0);
         return ret;
     };
     imports.wbg.__wbg_close_18f7f59ca0e610c6 = function() { return handleError(function (arg0) {
-        const ret = getObject(arg0).close();
-        return addHeapObject(ret);
+        const ret = arg0.close();
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_close_35d643897b189a00 = function(arg0) {
-        getObject(arg0).close();
+        arg0.close();
     };
     imports.wbg.__wbg_code_d8226b2133366406 = function(arg0, arg1) {
-        const ret = getObject(arg1).code;
+        const ret = arg1.code;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_colorMask_22d850d91e89df9f = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).colorMask(arg1 !== 0, arg2 !== 0, arg3 !== 0, arg4 !== 0);
+        arg0.colorMask(arg1 !== 0, arg2 !== 0, arg3 !== 0, arg4 !== 0);
     };
     imports.wbg.__wbg_colorMask_a6068fae89c17ceb = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).colorMask(arg1 !== 0, arg2 !== 0, arg3 !== 0, arg4 !== 0);
+        arg0.colorMask(arg1 !== 0, arg2 !== 0, arg3 !== 0, arg4 !== 0);
     };
     imports.wbg.__wbg_compileShader_5e41ecd397194c21 = function(arg0, arg1) {
-        getObject(arg0).compileShader(getObject(arg1));
+        arg0.compileShader(arg1);
     };
     imports.wbg.__wbg_compileShader_6868fa6a842f0911 = function(arg0, arg1) {
-        getObject(arg0).compileShader(getObject(arg1));
+        arg0.compileShader(arg1);
     };
     imports.wbg.__wbg_compressedTexSubImage2D_216f6eec167da8d7 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) {
-        getObject(arg0).compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, getObject(arg8));
+        arg0.compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8);
     };
     imports.wbg.__wbg_compressedTexSubImage2D_4b50d2a94bf4ea60 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8, arg9);
+        arg0.compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8, arg9);
     };
     imports.wbg.__wbg_compressedTexSubImage2D_9b0c2c785026006a = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) {
-        getObject(arg0).compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, getObject(arg8));
+        arg0.compressedTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8);
     };
     imports.wbg.__wbg_compressedTexSubImage3D_8102ea4403fef5ce = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) {
-        getObject(arg0).compressedTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, getObject(arg10));
+        arg0.compressedTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10);
     };
     imports.wbg.__wbg_compressedTexSubImage3D_bf2bdb940e26bc8c = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).compressedTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10, arg11);
+        arg0.compressedTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10, arg11);
     };
     imports.wbg.__wbg_connect_7142da86ecc640ca = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).connect(getObject(arg1));
-        return addHeapObject(ret);
+        const ret = arg0.connect(arg1);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_connected_31103f68eeb207c8 = function(arg0) {
-        const ret = getObject(arg0).connected;
+        const ret = arg0.connected;
         return ret;
     };
     imports.wbg.__wbg_contains_6947e163e2a3c092 = function(arg0, arg1) {
-        const ret = getObject(arg0).contains(getObject(arg1));
+        const ret = arg0.contains(arg1);
         return ret;
     };
     imports.wbg.__wbg_contentRect_7aaa87e16fd2882d = function(arg0) {
-        const ret = getObject(arg0).contentRect;
-        return addHeapObject(ret);
+        const ret = arg0.contentRect;
+        return ret;
     };
     imports.wbg.__wbg_copyBufferSubData_ff213d67aa3b1f82 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).copyBufferSubData(arg1 >>> 0, arg2 >>> 0, arg3, arg4, arg5);
+        arg0.copyBufferSubData(arg1 >>> 0, arg2 >>> 0, arg3, arg4, arg5);
     };
     imports.wbg.__wbg_copyTexSubImage2D_4b37e79c31b798b1 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) {
-        getObject(arg0).copyTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8);
+        arg0.copyTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8);
     };
     imports.wbg.__wbg_copyTexSubImage2D_a29106dfb31dd17c = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) {
-        getObject(arg0).copyTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8);
+        arg0.copyTexSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8);
     };
     imports.wbg.__wbg_copyTexSubImage3D_f8350e268c143688 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).copyTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
+        arg0.copyTexSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
     };
     imports.wbg.__wbg_copyToChannel_6155b9e03a0dd40e = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        getObject(arg0).copyToChannel(getArrayF32FromWasm0(arg1, arg2), arg3);
+        arg0.copyToChannel(getArrayF32FromWasm0(arg1, arg2), arg3);
     }, arguments) };
     imports.wbg.__wbg_createBufferSource_9c2e3ab129bbf2c7 = function() { return handleError(function (arg0) {
-        const ret = getObject(arg0).createBufferSource();
-        return addHeapObject(ret);
+        const ret = arg0.createBufferSource();
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_createBuffer_1e646d14cebbb438 = function(arg0) {
-        const ret = getObject(arg0).createBuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createBuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createBuffer_2a696fb8c0d07970 = function(arg0) {
-        const ret = getObject(arg0).createBuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createBuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createBuffer_5d8dc91119848920 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).createBuffer(arg1 >>> 0, arg2 >>> 0, arg3);
-        return addHeapObject(ret);
+        const ret = arg0.createBuffer(arg1 >>> 0, arg2 >>> 0, arg3);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_createElement_22b48bfb31a0c20e = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).createElement(getStringFromWasm0(arg1, arg2));
-        return addHeapObject(ret);
+        const ret = arg0.createElement(getStringFromWasm0(arg1, arg2));
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_createFramebuffer_163e192e1e53ec9b = function(arg0) {
-        const ret = getObject(arg0).createFramebuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createFramebuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createFramebuffer_1aa54479f7524ef5 = function(arg0) {
-        const ret = getObject(arg0).createFramebuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createFramebuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createObjectURL_06505af3e8787cc8 = function() { return handleError(function (arg0, arg1) {
-        const ret = URL.createObjectURL(getObject(arg1));
+        const ret = URL.createObjectURL(arg1);
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     }, arguments) };
     imports.wbg.__wbg_createProgram_5a5a92d23fdc2f1a = function(arg0) {
-        const ret = getObject(arg0).createProgram();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createProgram();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createProgram_b75025f0f1a4ef55 = function(arg0) {
-        const ret = getObject(arg0).createProgram();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createProgram();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createQuery_2816e95a473b5501 = function(arg0) {
-        const ret = getObject(arg0).createQuery();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createQuery();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createRenderbuffer_29e130c03bc689d3 = function(arg0) {
-        const ret = getObject(arg0).createRenderbuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createRenderbuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createRenderbuffer_b10004ae18d38014 = function(arg0) {
-        const ret = getObject(arg0).createRenderbuffer();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createRenderbuffer();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createSampler_50ae7c4dd1f9b066 = function(arg0) {
-        const ret = getObject(arg0).createSampler();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createSampler();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createShader_29c8e06db7e7211f = function(arg0, arg1) {
-        const ret = getObject(arg0).createShader(arg1 >>> 0);
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createShader(arg1 >>> 0);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createShader_8c3053457f874cdd = function(arg0, arg1) {
-        const ret = getObject(arg0).createShader(arg1 >>> 0);
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createShader(arg1 >>> 0);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createTexture_20f63b261993f581 = function(arg0) {
-        const ret = getObject(arg0).createTexture();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createTexture();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createTexture_facd2df68d8d0276 = function(arg0) {
-        const ret = getObject(arg0).createTexture();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createTexture();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createVertexArrayOES_89f45f4a4fde395f = function(arg0) {
-        const ret = getObject(arg0).createVertexArrayOES();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createVertexArrayOES();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_createVertexArray_70d7c4c62e00613d = function(arg0) {
-        const ret = getObject(arg0).createVertexArray();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.createVertexArray();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_crypto_ed58b8e10a292839 = function(arg0) {
-        const ret = getObject(arg0).crypto;
-        return addHeapObject(ret);
+        const ret = arg0.crypto;
+        return ret;
     };
     imports.wbg.__wbg_ctrlKey_60b29e015a543678 = function(arg0) {
-        const ret = getObject(arg0).ctrlKey;
+        const ret = arg0.ctrlKey;
         return ret;
     };
     imports.wbg.__wbg_ctrlKey_ab341328ab202d37 = function(arg0) {
-        const ret = getObject(arg0).ctrlKey;
+        const ret = arg0.ctrlKey;
         return ret;
     };
     imports.wbg.__wbg_cullFace_96a9dca4a5b1ca33 = function(arg0, arg1) {
-        getObject(arg0).cullFace(arg1 >>> 0);
+        arg0.cullFace(arg1 >>> 0);
     };
     imports.wbg.__wbg_cullFace_99bb4e31c974596d = function(arg0, arg1) {
-        getObject(arg0).cullFace(arg1 >>> 0);
+        arg0.cullFace(arg1 >>> 0);
     };
     imports.wbg.__wbg_currentTime_79a333b84b98fa11 = function(arg0) {
-        const ret = getObject(arg0).currentTime;
+        const ret = arg0.currentTime;
         return ret;
     };
     imports.wbg.__wbg_deleteBuffer_9d705222a7e77a7b = function(arg0, arg1) {
-        getObject(arg0).deleteBuffer(getObject(arg1));
+        arg0.deleteBuffer(arg1);
     };
     imports.wbg.__wbg_deleteBuffer_db0d7ecbaa97fa56 = function(arg0, arg1) {
-        getObject(arg0).deleteBuffer(getObject(arg1));
+        arg0.deleteBuffer(arg1);
     };
     imports.wbg.__wbg_deleteFramebuffer_69db238ea6b8a9a1 = function(arg0, arg1) {
-        getObject(arg0).deleteFramebuffer(getObject(arg1));
+        arg0.deleteFramebuffer(arg1);
     };
     imports.wbg.__wbg_deleteFramebuffer_ecf398bec452aaf9 = function(arg0, arg1) {
-        getObject(arg0).deleteFramebuffer(getObject(arg1));
+        arg0.deleteFramebuffer(arg1);
     };
     imports.wbg.__wbg_deleteProgram_cb18e0020d488bad = function(arg0, arg1) {
-        getObject(arg0).deleteProgram(getObject(arg1));
+        arg0.deleteProgram(arg1);
     };
     imports.wbg.__wbg_deleteProgram_e1a6c4b922e202e3 = function(arg0, arg1) {
-        getObject(arg0).deleteProgram(getObject(arg1));
+        arg0.deleteProgram(arg1);
     };
     imports.wbg.__wbg_deleteQuery_e083394da1710d6c = function(arg0, arg1) {
-        getObject(arg0).deleteQuery(getObject(arg1));
+        arg0.deleteQuery(arg1);
     };
     imports.wbg.__wbg_deleteRenderbuffer_05fa7f35ea76cafd = function(arg0, arg1) {
-        getObject(arg0).deleteRenderbuffer(getObject(arg1));
+        arg0.deleteRenderbuffer(arg1);
     };
     imports.wbg.__wbg_deleteRenderbuffer_2f1216e54aa2f248 = function(arg0, arg1) {
-        getObject(arg0).deleteRenderbuffer(getObject(arg1));
+        arg0.deleteRenderbuffer(arg1);
     };
     imports.wbg.__wbg_deleteSampler_e5c61c85993f9a69 = function(arg0, arg1) {
-        getObject(arg0).deleteSampler(getObject(arg1));
+        arg0.deleteSampler(arg1);
     };
     imports.wbg.__wbg_deleteShader_257caf93b24ac555 = function(arg0, arg1) {
-        getObject(arg0).deleteShader(getObject(arg1));
+        arg0.deleteShader(arg1);
     };
     imports.wbg.__wbg_deleteShader_fb86028e46cb069b = function(arg0, arg1) {
-        getObject(arg0).deleteShader(getObject(arg1));
+        arg0.deleteShader(arg1);
     };
     imports.wbg.__wbg_deleteSync_b5caedf06185d972 = function(arg0, arg1) {
-        getObject(arg0).deleteSync(getObject(arg1));
+        arg0.deleteSync(arg1);
     };
     imports.wbg.__wbg_deleteTexture_6913f682a09c8171 = function(arg0, arg1) {
-        getObject(arg0).deleteTexture(getObject(arg1));
+        arg0.deleteTexture(arg1);
     };
     imports.wbg.__wbg_deleteTexture_a29655740b1cbe33 = function(arg0, arg1) {
-        getObject(arg0).deleteTexture(getObject(arg1));
+        arg0.deleteTexture(arg1);
     };
     imports.wbg.__wbg_deleteVertexArrayOES_2def7ce37f8e89f2 = function(arg0, arg1) {
-        getObject(arg0).deleteVertexArrayOES(getObject(arg1));
+        arg0.deleteVertexArrayOES(arg1);
     };
     imports.wbg.__wbg_deleteVertexArray_a3bca8e15204ffed = function(arg0, arg1) {
-        getObject(arg0).deleteVertexArray(getObject(arg1));
+        arg0.deleteVertexArray(arg1);
     };
     imports.wbg.__wbg_deltaMode_a4cc321212f87817 = function(arg0) {
-        const ret = getObject(arg0).deltaMode;
+        const ret = arg0.deltaMode;
         return ret;
     };
     imports.wbg.__wbg_deltaX_27e2939a1af8c940 = function(arg0) {
-        const ret = getObject(arg0).deltaX;
+        const ret = arg0.deltaX;
         return ret;
     };
     imports.wbg.__wbg_deltaY_4bb52a4f0a7ad28b = function(arg0) {
-        const ret = getObject(arg0).deltaY;
+        const ret = arg0.deltaY;
         return ret;
     };
     imports.wbg.__wbg_depthFunc_657dd592930e7f2f = function(arg0, arg1) {
-        getObject(arg0).depthFunc(arg1 >>> 0);
+        arg0.depthFunc(arg1 >>> 0);
     };
     imports.wbg.__wbg_depthFunc_f9fd44a07ea8398e = function(arg0, arg1) {
-        getObject(arg0).depthFunc(arg1 >>> 0);
+        arg0.depthFunc(arg1 >>> 0);
     };
     imports.wbg.__wbg_depthMask_87195357e4f7df17 = function(arg0, arg1) {
-        getObject(arg0).depthMask(arg1 !== 0);
+        arg0.depthMask(arg1 !== 0);
     };
     imports.wbg.__wbg_depthMask_bac7f6b455b6ddeb = function(arg0, arg1) {
-        getObject(arg0).depthMask(arg1 !== 0);
+        arg0.depthMask(arg1 !== 0);
     };
     imports.wbg.__wbg_depthRange_d947e824a28dc9a1 = function(arg0, arg1, arg2) {
-        getObject(arg0).depthRange(arg1, arg2);
+        arg0.depthRange(arg1, arg2);
     };
     imports.wbg.__wbg_depthRange_f71c79182f666e45 = function(arg0, arg1, arg2) {
-        getObject(arg0).depthRange(arg1, arg2);
+        arg0.depthRange(arg1, arg2);
     };
     imports.wbg.__wbg_destination_65078518eae2eca5 = function(arg0) {
-        const ret = getObject(arg0).destination;
-        return addHeapObject(ret);
+        const ret = arg0.destination;
+        return ret;
     };
     imports.wbg.__wbg_devicePixelContentBoxSize_1ea2c6145730b8c0 = function(arg0) {
-        const ret = getObject(arg0).devicePixelContentBoxSize;
-        return addHeapObject(ret);
+        const ret = arg0.devicePixelContentBoxSize;
+        return ret;
     };
     imports.wbg.__wbg_devicePixelRatio_f4eb7cbe3a812de0 = function(arg0) {
-        const ret = getObject(arg0).devicePixelRatio;
+        const ret = arg0.devicePixelRatio;
         return ret;
     };
     imports.wbg.__wbg_disableVertexAttribArray_c513f1fff3cb73f2 = function(arg0, arg1) {
-        getObject(arg0).disableVertexAttribArray(arg1 >>> 0);
+        arg0.disableVertexAttribArray(arg1 >>> 0);
     };
     imports.wbg.__wbg_disableVertexAttribArray_e7ff41dc0c3eaf1f = function(arg0, arg1) {
-        getObject(arg0).disableVertexAttribArray(arg1 >>> 0);
+        arg0.disableVertexAttribArray(arg1 >>> 0);
     };
     imports.wbg.__wbg_disable_4c1cedffa6646166 = function(arg0, arg1) {
-        getObject(arg0).disable(arg1 >>> 0);
+        arg0.disable(arg1 >>> 0);
     };
     imports.wbg.__wbg_disable_5320561e5cb15f08 = function(arg0, arg1) {
-        getObject(arg0).disable(arg1 >>> 0);
+        arg0.disable(arg1 >>> 0);
     };
     imports.wbg.__wbg_disconnect_8b7c928b6057719b = function(arg0) {
-        getObject(arg0).disconnect();
+        arg0.disconnect();
     };
     imports.wbg.__wbg_disconnect_c45e8044053eddf3 = function(arg0) {
-        getObject(arg0).disconnect();
+        arg0.disconnect();
     };
     imports.wbg.__wbg_document_c488ca7509cc6938 = function(arg0) {
-        const ret = getObject(arg0).document;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.document;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_drawArraysInstancedANGLE_55c83db2758c6ab4 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).drawArraysInstancedANGLE(arg1 >>> 0, arg2, arg3, arg4);
+        arg0.drawArraysInstancedANGLE(arg1 >>> 0, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_drawArraysInstanced_11f863da4daf24d0 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).drawArraysInstanced(arg1 >>> 0, arg2, arg3, arg4);
+        arg0.drawArraysInstanced(arg1 >>> 0, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_drawArrays_87e9bb0e2fb3e0fa = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).drawArrays(arg1 >>> 0, arg2, arg3);
+        arg0.drawArrays(arg1 >>> 0, arg2, arg3);
     };
     imports.wbg.__wbg_drawArrays_f5b0f0a0dc392c3f = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).drawArrays(arg1 >>> 0, arg2, arg3);
+        arg0.drawArrays(arg1 >>> 0, arg2, arg3);
     };
     imports.wbg.__wbg_drawBuffersWEBGL_22730193cdce9696 = function(arg0, arg1) {
-        getObject(arg0).drawBuffersWEBGL(getObject(arg1));
+        arg0.drawBuffersWEBGL(arg1);
     };
     imports.wbg.__wbg_drawBuffers_f256d529aecd01f2 = function(arg0, arg1) {
-        getObject(arg0).drawBuffers(getObject(arg1));
+        arg0.drawBuffers(arg1);
     };
     imports.wbg.__wbg_drawElementsInstancedANGLE_db0bafff77affb0d = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).drawElementsInstancedANGLE(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
+        arg0.drawElementsInstancedANGLE(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_drawElementsInstanced_e87f1c69165df984 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).drawElementsInstanced(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
+        arg0.drawElementsInstanced(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_enableVertexAttribArray_0ce3052ae5f3f84a = function(arg0, arg1) {
-        getObject(arg0).enableVertexAttribArray(arg1 >>> 0);
+        arg0.enableVertexAttribArray(arg1 >>> 0);
     };
     imports.wbg.__wbg_enableVertexAttribArray_2bb681a583bf0dbe = function(arg0, arg1) {
-        getObject(arg0).enableVertexAttribArray(arg1 >>> 0);
+        arg0.enableVertexAttribArray(arg1 >>> 0);
     };
     imports.wbg.__wbg_enable_8d6ea7489b31dabd = function(arg0, arg1) {
-        getObject(arg0).enable(arg1 >>> 0);
+        arg0.enable(arg1 >>> 0);
     };
     imports.wbg.__wbg_enable_bb868e19d5c88d56 = function(arg0, arg1) {
-        getObject(arg0).enable(arg1 >>> 0);
+        arg0.enable(arg1 >>> 0);
     };
     imports.wbg.__wbg_endQuery_b4f2566f7afc4117 = function(arg0, arg1) {
-        getObject(arg0).endQuery(arg1 >>> 0);
+        arg0.endQuery(arg1 >>> 0);
     };
     imports.wbg.__wbg_error_7534b8e9a36f1ab4 = function(arg0, arg1) {
         let deferred0_0;
@@ -916,249 +1031,260 @@ function __wbg_get_imports() {
         }
     };
     imports.wbg.__wbg_error_f0dde81ae1e4cfea = function(arg0, arg1) {
-        console.error(getObject(arg0), getObject(arg1));
+        console.error(arg0, arg1);
     };
     imports.wbg.__wbg_eval_3f5a1e931805582e = function() { return handleError(function (arg0, arg1) {
         const ret = eval(getStringFromWasm0(arg0, arg1));
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_exec_e5b487b382193a31 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).exec(getStringFromWasm0(arg1, arg2));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.exec(getStringFromWasm0(arg1, arg2));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_exitFullscreen_e0a94c24ed3326ef = function(arg0) {
-        getObject(arg0).exitFullscreen();
+        arg0.exitFullscreen();
     };
     imports.wbg.__wbg_exitPointerLock_2d8adaf1c923990f = function(arg0) {
-        getObject(arg0).exitPointerLock();
+        arg0.exitPointerLock();
     };
     imports.wbg.__wbg_fenceSync_68dcf439f52decac = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).fenceSync(arg1 >>> 0, arg2 >>> 0);
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.fenceSync(arg1 >>> 0, arg2 >>> 0);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_fetch_22f6188f309225ff = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).fetch(getStringFromWasm0(arg1, arg2));
-        return addHeapObject(ret);
+        const ret = arg0.fetch(getStringFromWasm0(arg1, arg2));
+        return ret;
     };
     imports.wbg.__wbg_fetch_588e3e884fcdc8c8 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).fetch(getStringFromWasm0(arg1, arg2));
-        return addHeapObject(ret);
+        const ret = arg0.fetch(getStringFromWasm0(arg1, arg2));
+        return ret;
     };
     imports.wbg.__wbg_focus_c71947fc3fe22147 = function() { return handleError(function (arg0) {
-        getObject(arg0).focus();
+        arg0.focus();
     }, arguments) };
     imports.wbg.__wbg_framebufferRenderbuffer_308ddbcaaed66ff2 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).framebufferRenderbuffer(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, getObject(arg4));
+        arg0.framebufferRenderbuffer(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4);
     };
     imports.wbg.__wbg_framebufferRenderbuffer_a23d035c46e3e192 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).framebufferRenderbuffer(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, getObject(arg4));
+        arg0.framebufferRenderbuffer(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4);
     };
     imports.wbg.__wbg_framebufferTexture2D_170b0f8c792e246f = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).framebufferTexture2D(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, getObject(arg4), arg5);
+        arg0.framebufferTexture2D(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_framebufferTexture2D_5366ecb050293d09 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).framebufferTexture2D(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, getObject(arg4), arg5);
+        arg0.framebufferTexture2D(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_framebufferTextureLayer_daccfb6fb9396578 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).framebufferTextureLayer(arg1 >>> 0, arg2 >>> 0, getObject(arg3), arg4, arg5);
+        arg0.framebufferTextureLayer(arg1 >>> 0, arg2 >>> 0, arg3, arg4, arg5);
     };
     imports.wbg.__wbg_framebufferTextureMultiviewOVR_e2282e87fdf2564f = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6) {
-        getObject(arg0).framebufferTextureMultiviewOVR(arg1 >>> 0, arg2 >>> 0, getObject(arg3), arg4, arg5, arg6);
+        arg0.framebufferTextureMultiviewOVR(arg1 >>> 0, arg2 >>> 0, arg3, arg4, arg5, arg6);
     };
     imports.wbg.__wbg_frontFace_7b469405c0e889ee = function(arg0, arg1) {
-        getObject(arg0).frontFace(arg1 >>> 0);
+        arg0.frontFace(arg1 >>> 0);
     };
     imports.wbg.__wbg_frontFace_993e921eaef39c45 = function(arg0, arg1) {
-        getObject(arg0).frontFace(arg1 >>> 0);
+        arg0.frontFace(arg1 >>> 0);
     };
     imports.wbg.__wbg_fullscreenElement_a613ad02fa918fb4 = function(arg0) {
-        const ret = getObject(arg0).fullscreenElement;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.fullscreenElement;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_getBoundingClientRect_d5aa7383cf5c9a73 = function(arg0) {
-        const ret = getObject(arg0).getBoundingClientRect();
-        return addHeapObject(ret);
+        const ret = arg0.getBoundingClientRect();
+        return ret;
     };
     imports.wbg.__wbg_getBufferSubData_5102d8a217d0774c = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).getBufferSubData(arg1 >>> 0, arg2, getObject(arg3));
+        arg0.getBufferSubData(arg1 >>> 0, arg2, arg3);
     };
     imports.wbg.__wbg_getCoalescedEvents_1372a1922f78401a = function(arg0) {
-        const ret = getObject(arg0).getCoalescedEvents;
-        return addHeapObject(ret);
+        const ret = arg0.getCoalescedEvents;
+        return ret;
     };
     imports.wbg.__wbg_getCoalescedEvents_3736b5e5d63bed7b = function(arg0) {
-        const ret = getObject(arg0).getCoalescedEvents();
-        return addHeapObject(ret);
+        const ret = arg0.getCoalescedEvents();
+        return ret;
     };
     imports.wbg.__wbg_getComputedStyle_c3a9de7674a38310 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).getComputedStyle(getObject(arg1));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getComputedStyle(arg1);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_getContext_74e4f1560c2a2fae = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).getContext(getStringFromWasm0(arg1, arg2), getObject(arg3));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getContext(getStringFromWasm0(arg1, arg2), arg3);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_getContext_b22f05fca7ddb494 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).getContext(getStringFromWasm0(arg1, arg2), getObject(arg3));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getContext(getStringFromWasm0(arg1, arg2), arg3);
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_getExtension_28666bdc87d23aca = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).getExtension(getStringFromWasm0(arg1, arg2));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getExtension(getStringFromWasm0(arg1, arg2));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_getGamepads_6638806105277531 = function() { return handleError(function (arg0) {
-        const ret = getObject(arg0).getGamepads();
-        return addHeapObject(ret);
+        const ret = arg0.getGamepads();
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_getIndexedParameter_f9e7220a3bb8862c = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).getIndexedParameter(arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getIndexedParameter(arg1 >>> 0, arg2 >>> 0);
+        return ret;
+    }, arguments) };
+    imports.wbg.__wbg_getItem_561976eef304cebe = function() { return handleError(function (arg0, arg1, arg2, arg3) {
+        const ret = arg1.getItem(getStringFromWasm0(arg2, arg3));
+        var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+        getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     }, arguments) };
     imports.wbg.__wbg_getOwnPropertyDescriptor_c701b185423f5b7e = function(arg0, arg1) {
-        const ret = Object.getOwnPropertyDescriptor(getObject(arg0), getObject(arg1));
-        return addHeapObject(ret);
+        const ret = Object.getOwnPropertyDescriptor(arg0, arg1);
+        return ret;
     };
     imports.wbg.__wbg_getParameter_304cffb9a759dc04 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).getParameter(arg1 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getParameter(arg1 >>> 0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_getParameter_fd65bc6ff1b0ffd9 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).getParameter(arg1 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getParameter(arg1 >>> 0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_getProgramInfoLog_032aac3e6f3a253c = function(arg0, arg1, arg2) {
-        const ret = getObject(arg1).getProgramInfoLog(getObject(arg2));
+        const ret = arg1.getProgramInfoLog(arg2);
         var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_getProgramInfoLog_039168c2aed8d3fe = function(arg0, arg1, arg2) {
-        const ret = getObject(arg1).getProgramInfoLog(getObject(arg2));
+        const ret = arg1.getProgramInfoLog(arg2);
         var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_getProgramParameter_70b22019524689fa = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getProgramParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getProgramParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getProgramParameter_9b3bdf8d90159edb = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getProgramParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getProgramParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getPropertyValue_e87121b8549f72d5 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg1).getPropertyValue(getStringFromWasm0(arg2, arg3));
+        const ret = arg1.getPropertyValue(getStringFromWasm0(arg2, arg3));
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     }, arguments) };
     imports.wbg.__wbg_getQueryParameter_e7b02fe5fd64baa5 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getQueryParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getQueryParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getRandomValues_bcb4912f16000dc4 = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).getRandomValues(getObject(arg1));
+        arg0.getRandomValues(arg1);
     }, arguments) };
     imports.wbg.__wbg_getShaderInfoLog_5c7d45bafe3be3dd = function(arg0, arg1, arg2) {
-        const ret = getObject(arg1).getShaderInfoLog(getObject(arg2));
+        const ret = arg1.getShaderInfoLog(arg2);
         var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_getShaderInfoLog_d2cc881ce343a733 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg1).getShaderInfoLog(getObject(arg2));
+        const ret = arg1.getShaderInfoLog(arg2);
         var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_getShaderParameter_6d0578dd9f58b684 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getShaderParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getShaderParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getShaderParameter_c50fbeadf9ef6879 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getShaderParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getShaderParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getSupportedExtensions_fca342bac23691db = function(arg0) {
-        const ret = getObject(arg0).getSupportedExtensions();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getSupportedExtensions();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_getSupportedProfiles_b2cc3aec569a65fa = function(arg0) {
-        const ret = getObject(arg0).getSupportedProfiles();
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getSupportedProfiles();
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_getSyncParameter_5688138f66b57b16 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).getSyncParameter(getObject(arg1), arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.getSyncParameter(arg1, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_getUniformBlockIndex_b53609b8ecff8b48 = function(arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).getUniformBlockIndex(getObject(arg1), getStringFromWasm0(arg2, arg3));
+        const ret = arg0.getUniformBlockIndex(arg1, getStringFromWasm0(arg2, arg3));
         return ret;
     };
     imports.wbg.__wbg_getUniformLocation_852fbe42afe106ff = function(arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).getUniformLocation(getObject(arg1), getStringFromWasm0(arg2, arg3));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getUniformLocation(arg1, getStringFromWasm0(arg2, arg3));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_getUniformLocation_9d46a65011600cce = function(arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg0).getUniformLocation(getObject(arg1), getStringFromWasm0(arg2, arg3));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.getUniformLocation(arg1, getStringFromWasm0(arg2, arg3));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_get_68aa371864aa301a = function(arg0, arg1) {
-        const ret = getObject(arg0)[arg1 >>> 0];
-        return addHeapObject(ret);
+        const ret = arg0[arg1 >>> 0];
+        return ret;
     };
     imports.wbg.__wbg_globalThis_1e2ac1d6eee845b3 = function() { return handleError(function () {
         const ret = globalThis.globalThis;
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_global_f25a574ae080367c = function() { return handleError(function () {
         const ret = global.global;
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_height_4065e49e5ec4c4c1 = function(arg0) {
-        const ret = getObject(arg0).height;
+        const ret = arg0.height;
         return ret;
     };
     imports.wbg.__wbg_height_51a97623165c9beb = function(arg0) {
-        const ret = getObject(arg0).height;
+        const ret = arg0.height;
         return ret;
     };
     imports.wbg.__wbg_height_74bc8ec7548ff6b6 = function(arg0) {
-        const ret = getObject(arg0).height;
+        const ret = arg0.height;
         return ret;
     };
     imports.wbg.__wbg_height_e509816ec3fdf5b1 = function(arg0) {
-        const ret = getObject(arg0).height;
+        const ret = arg0.height;
+        return ret;
+    };
+    imports.wbg.__wbg_hidden_62b8112083edecbf = function(arg0) {
+        const ret = arg0.hidden;
         return ret;
     };
     imports.wbg.__wbg_id_cf47b38e5ed9c36c = function(arg0, arg1) {
-        const ret = getObject(arg1).id;
+        const ret = arg1.id;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_includes_959087f4155febdf = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).includes(getObject(arg1), arg2);
+        const ret = arg0.includes(arg1, arg2);
         return ret;
     };
     imports.wbg.__wbg_index_a82f5d0cb7999974 = function(arg0) {
-        const ret = getObject(arg0).index;
+        const ret = arg0.index;
         return ret;
     };
     imports.wbg.__wbg_inlineSize_6f8d0983462c2919 = function(arg0) {
-        const ret = getObject(arg0).inlineSize;
+        const ret = arg0.inlineSize;
         return ret;
     };
     imports.wbg.__wbg_instanceof_DomException_db7a79d719cae989 = function(arg0) {
         let result;
         try {
-            result = getObject(arg0) instanceof DOMException;
+            result = arg0 instanceof DOMException;
         } catch (_) {
             result = false;
         }
@@ -1168,7 +1294,7 @@ function __wbg_get_imports() {
     imports.wbg.__wbg_instanceof_HtmlCanvasElement_9db0dfd54b2c5330 = function(arg0) {
         let result;
         try {
-            result = getObject(arg0) instanceof HTMLCanvasElement;
+            result = arg0 instanceof HTMLCanvasElement;
         } catch (_) {
             result = false;
         }
@@ -1178,7 +1304,7 @@ function __wbg_get_imports() {
     imports.wbg.__wbg_instanceof_Response_0ec26bd2f8a75ca2 = function(arg0) {
         let result;
         try {
-            result = getObject(arg0) instanceof Response;
+            result = arg0 instanceof Response;
         } catch (_) {
             result = false;
         }
@@ -1188,7 +1314,7 @@ function __wbg_get_imports() {
     imports.wbg.__wbg_instanceof_WebGl2RenderingContext_888701598b82d45d = function(arg0) {
         let result;
         try {
-            result = getObject(arg0) instanceof WebGL2RenderingContext;
+            result = arg0 instanceof WebGL2RenderingContext;
         } catch (_) {
             result = false;
         }
@@ -1198,7 +1324,7 @@ function __wbg_get_imports() {
     imports.wbg.__wbg_instanceof_Window_a959820eb267fe22 = function(arg0) {
         let result;
         try {
-            result = getObject(arg0) instanceof Window;
+            result = arg0 instanceof Window;
         } catch (_) {
             result = false;
         }
@@ -1206,43 +1332,47 @@ function __wbg_get_imports() {
         return ret;
     };
     imports.wbg.__wbg_invalidateFramebuffer_f4a994f4a8535cca = function() { return handleError(function (arg0, arg1, arg2) {
-        getObject(arg0).invalidateFramebuffer(arg1 >>> 0, getObject(arg2));
+        arg0.invalidateFramebuffer(arg1 >>> 0, arg2);
     }, arguments) };
     imports.wbg.__wbg_isIntersecting_9059d5bcaf29f415 = function(arg0) {
-        const ret = getObject(arg0).isIntersecting;
+        const ret = arg0.isIntersecting;
         return ret;
     };
     imports.wbg.__wbg_isSecureContext_be7df9481b21ad0d = function(arg0) {
-        const ret = getObject(arg0).isSecureContext;
+        const ret = arg0.isSecureContext;
         return ret;
     };
     imports.wbg.__wbg_is_20768e55ad2a7c3f = function(arg0, arg1) {
-        const ret = Object.is(getObject(arg0), getObject(arg1));
+        const ret = Object.is(arg0, arg1);
         return ret;
     };
     imports.wbg.__wbg_key_02315cd3f595756b = function(arg0, arg1) {
-        const ret = getObject(arg1).key;
+        const ret = arg1.key;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_length_2e63ba34c4121df5 = function(arg0) {
-        const ret = getObject(arg0).length;
+        const ret = arg0.length;
         return ret;
     };
     imports.wbg.__wbg_length_e74df4881604f1d9 = function(arg0) {
-        const ret = getObject(arg0).length;
+        const ret = arg0.length;
         return ret;
     };
     imports.wbg.__wbg_linkProgram_575f761eda0a14bc = function(arg0, arg1) {
-        getObject(arg0).linkProgram(getObject(arg1));
+        arg0.linkProgram(arg1);
     };
     imports.wbg.__wbg_linkProgram_5eee13e603e9af41 = function(arg0, arg1) {
-        getObject(arg0).linkProgram(getObject(arg1));
+        arg0.linkProgram(arg1);
     };
+    imports.wbg.__wbg_localStorage_05bfbeeb8946b5bf = function() { return handleError(function (arg0) {
+        const ret = arg0.localStorage;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+    }, arguments) };
     imports.wbg.__wbg_location_e9eba129bf0612a5 = function(arg0) {
-        const ret = getObject(arg0).location;
+        const ret = arg0.location;
         return ret;
     };
     imports.wbg.__wbg_log_0cc1b7768397bcfe = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) {
@@ -1268,22 +1398,22 @@ function __wbg_get_imports() {
         }
     };
     imports.wbg.__wbg_mapping_e9e96f5f63ee828d = function(arg0) {
-        const ret = getObject(arg0).mapping;
+        const ret = arg0.mapping;
         return (__wbindgen_enum_GamepadMappingType.indexOf(ret) + 1 || 3) - 1;
     };
     imports.wbg.__wbg_mark_7438147ce31e9d4b = function(arg0, arg1) {
         performance.mark(getStringFromWasm0(arg0, arg1));
     };
     imports.wbg.__wbg_matchMedia_0be65181eeae951c = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).matchMedia(getStringFromWasm0(arg1, arg2));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.matchMedia(getStringFromWasm0(arg1, arg2));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_matches_254463383aee4688 = function(arg0) {
-        const ret = getObject(arg0).matches;
+        const ret = arg0.matches;
         return ret;
     };
     imports.wbg.__wbg_maxChannelCount_a2b9e63650077c1e = function(arg0) {
-        const ret = getObject(arg0).maxChannelCount;
+        const ret = arg0.maxChannelCount;
         return ret;
     };
     imports.wbg.__wbg_measure_fb7825c11612c823 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
@@ -1303,133 +1433,133 @@ function __wbg_get_imports() {
         }
     }, arguments) };
     imports.wbg.__wbg_media_8db09f09635587da = function(arg0, arg1) {
-        const ret = getObject(arg1).media;
+        const ret = arg1.media;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_message_a14b869acc83442f = function(arg0, arg1) {
-        const ret = getObject(arg1).message;
+        const ret = arg1.message;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_metaKey_34d5658170ffb3ee = function(arg0) {
-        const ret = getObject(arg0).metaKey;
+        const ret = arg0.metaKey;
         return ret;
     };
     imports.wbg.__wbg_metaKey_6c8e9228e8dda152 = function(arg0) {
-        const ret = getObject(arg0).metaKey;
+        const ret = arg0.metaKey;
         return ret;
     };
     imports.wbg.__wbg_movementX_e32c342d96d1c701 = function(arg0) {
-        const ret = getObject(arg0).movementX;
+        const ret = arg0.movementX;
         return ret;
     };
     imports.wbg.__wbg_movementY_136c6febb976ca3b = function(arg0) {
-        const ret = getObject(arg0).movementY;
+        const ret = arg0.movementY;
         return ret;
     };
     imports.wbg.__wbg_msCrypto_0a36e2ec3a343d26 = function(arg0) {
-        const ret = getObject(arg0).msCrypto;
-        return addHeapObject(ret);
+        const ret = arg0.msCrypto;
+        return ret;
     };
     imports.wbg.__wbg_navigator_da495c9e52e160b1 = function(arg0) {
-        const ret = getObject(arg0).navigator;
-        return addHeapObject(ret);
+        const ret = arg0.navigator;
+        return ret;
     };
     imports.wbg.__wbg_new_076cac58bb698dd4 = function() {
         const ret = new Object();
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_new_0c28e72025e00594 = function() {
         const ret = new Array();
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_new_23362fa370a0a372 = function(arg0) {
-        const ret = new Uint8Array(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = new Uint8Array(arg0);
+        return ret;
     };
     imports.wbg.__wbg_new_238671e08bf4fcbb = function() { return handleError(function () {
         const ret = new MessageChannel();
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_new_2d898f60f528ddf5 = function(arg0, arg1, arg2, arg3) {
         const ret = new RegExp(getStringFromWasm0(arg0, arg1), getStringFromWasm0(arg2, arg3));
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_new_4b15073a88792687 = function() { return handleError(function (arg0) {
-        const ret = new ResizeObserver(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = new ResizeObserver(arg0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_new_8a6f238a6ece86ea = function() {
         const ret = new Error();
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_new_93cf40e4f48fe902 = function() { return handleError(function () {
         const ret = new AbortController();
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_new_b4a18f0a618c89f2 = function() { return handleError(function (arg0, arg1) {
         const ret = new Worker(getStringFromWasm0(arg0, arg1));
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_new_c94d990936d8ea5d = function() { return handleError(function (arg0) {
-        const ret = new IntersectionObserver(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = new IntersectionObserver(arg0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_newnoargs_19a249f4eceaaac3 = function(arg0, arg1) {
         const ret = new Function(getStringFromWasm0(arg0, arg1));
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_24ff09a6b37a856f = function(arg0, arg1, arg2) {
-        const ret = new Int16Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Int16Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_25d3cac011b6e2d5 = function(arg0, arg1, arg2) {
-        const ret = new Uint32Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Uint32Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_457c61bfe0fb7b8c = function(arg0, arg1, arg2) {
-        const ret = new Int32Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Int32Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_9f48300371c8802a = function(arg0, arg1, arg2) {
-        const ret = new Uint16Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Uint16Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_a6087a94c7bfea61 = function(arg0, arg1, arg2) {
-        const ret = new Int8Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Int8Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_c280c15b00e018cd = function(arg0, arg1, arg2) {
-        const ret = new Float32Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Float32Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithbyteoffsetandlength_ee8def7000b7b2be = function(arg0, arg1, arg2) {
-        const ret = new Uint8Array(getObject(arg0), arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = new Uint8Array(arg0, arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_newwithcontextoptions_cda5c6bcc1b57f6b = function() { return handleError(function (arg0) {
-        const ret = new lAudioContext(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = new lAudioContext(arg0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_newwithlength_91de49dea5643c87 = function(arg0) {
         const ret = new Uint8Array(arg0 >>> 0);
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_newwithstrsequenceandoptions_af096ce40d9d9ac8 = function() { return handleError(function (arg0, arg1) {
-        const ret = new Blob(getObject(arg0), getObject(arg1));
-        return addHeapObject(ret);
+        const ret = new Blob(arg0, arg1);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_node_02999533c4ea02e3 = function(arg0) {
-        const ret = getObject(arg0).node;
-        return addHeapObject(ret);
+        const ret = arg0.node;
+        return ret;
     };
     imports.wbg.__wbg_now_2c95c9de01293173 = function(arg0) {
-        const ret = getObject(arg0).now();
+        const ret = arg0.now();
         return ret;
     };
     imports.wbg.__wbg_now_5cf792f3426feb88 = function() {
@@ -1437,623 +1567,630 @@ function __wbg_get_imports() {
         return ret;
     };
     imports.wbg.__wbg_observe_e2208b07fe315de7 = function(arg0, arg1) {
-        getObject(arg0).observe(getObject(arg1));
+        arg0.observe(arg1);
     };
     imports.wbg.__wbg_observe_ec91d62599b7772b = function(arg0, arg1) {
-        getObject(arg0).observe(getObject(arg1));
+        arg0.observe(arg1);
     };
     imports.wbg.__wbg_observe_fd48955513eca909 = function(arg0, arg1, arg2) {
-        getObject(arg0).observe(getObject(arg1), getObject(arg2));
+        arg0.observe(arg1, arg2);
     };
     imports.wbg.__wbg_of_2f6232d86af7992c = function(arg0, arg1) {
-        const ret = Array.of(getObject(arg0), getObject(arg1));
-        return addHeapObject(ret);
+        const ret = Array.of(arg0, arg1);
+        return ret;
     };
     imports.wbg.__wbg_of_5ae3a2d893e18853 = function(arg0) {
-        const ret = Array.of(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = Array.of(arg0);
+        return ret;
     };
     imports.wbg.__wbg_offsetX_d6957d052a0d4b11 = function(arg0) {
-        const ret = getObject(arg0).offsetX;
+        const ret = arg0.offsetX;
         return ret;
     };
     imports.wbg.__wbg_offsetY_076edde514bf80f4 = function(arg0) {
-        const ret = getObject(arg0).offsetY;
+        const ret = arg0.offsetY;
         return ret;
     };
+    imports.wbg.__wbg_parse_2163a08a1ba066f1 = function() { return handleError(function (arg0, arg1) {
+        const ret = JSON.parse(getStringFromWasm0(arg0, arg1));
+        return ret;
+    }, arguments) };
     imports.wbg.__wbg_performance_7a3ffd0b17f663ad = function(arg0) {
-        const ret = getObject(arg0).performance;
-        return addHeapObject(ret);
+        const ret = arg0.performance;
+        return ret;
     };
     imports.wbg.__wbg_persisted_7e044507e52804d9 = function(arg0) {
-        const ret = getObject(arg0).persisted;
+        const ret = arg0.persisted;
         return ret;
     };
     imports.wbg.__wbg_pixelStorei_198b92c3e346678a = function(arg0, arg1, arg2) {
-        getObject(arg0).pixelStorei(arg1 >>> 0, arg2);
+        arg0.pixelStorei(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_pixelStorei_9c4cb0a4b040b41d = function(arg0, arg1, arg2) {
-        getObject(arg0).pixelStorei(arg1 >>> 0, arg2);
+        arg0.pixelStorei(arg1 >>> 0, arg2);
     };
     imports.wbg.__wbg_play_e7eb90a843114e0d = function(arg0) {
-        getObject(arg0).play();
+        arg0.play();
     };
     imports.wbg.__wbg_pointerId_a2cbd2cdd6da90b2 = function(arg0) {
-        const ret = getObject(arg0).pointerId;
+        const ret = arg0.pointerId;
         return ret;
     };
     imports.wbg.__wbg_pointerType_1b74686427cdec29 = function(arg0, arg1) {
-        const ret = getObject(arg1).pointerType;
+        const ret = arg1.pointerType;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_polygonOffset_7ec31dee95d41171 = function(arg0, arg1, arg2) {
-        getObject(arg0).polygonOffset(arg1, arg2);
+        arg0.polygonOffset(arg1, arg2);
     };
     imports.wbg.__wbg_polygonOffset_c6cbc8093770e840 = function(arg0, arg1, arg2) {
-        getObject(arg0).polygonOffset(arg1, arg2);
+        arg0.polygonOffset(arg1, arg2);
     };
     imports.wbg.__wbg_port1_6f5b26fd23d19536 = function(arg0) {
-        const ret = getObject(arg0).port1;
-        return addHeapObject(ret);
+        const ret = arg0.port1;
+        return ret;
     };
     imports.wbg.__wbg_port2_2eb4c961d6df5816 = function(arg0) {
-        const ret = getObject(arg0).port2;
-        return addHeapObject(ret);
+        const ret = arg0.port2;
+        return ret;
     };
     imports.wbg.__wbg_postMessage_33dcf5caa3589c64 = function() { return handleError(function (arg0, arg1, arg2) {
-        getObject(arg0).postMessage(getObject(arg1), getObject(arg2));
+        arg0.postMessage(arg1, arg2);
     }, arguments) };
     imports.wbg.__wbg_postMessage_3e7f3215320b4a9c = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).postMessage(getObject(arg1));
+        arg0.postMessage(arg1);
     }, arguments) };
     imports.wbg.__wbg_postTask_9ba4c3cedae00b38 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).postTask(getObject(arg1), getObject(arg2));
-        return addHeapObject(ret);
+        const ret = arg0.postTask(arg1, arg2);
+        return ret;
     };
     imports.wbg.__wbg_pressed_44f3fd351d30cbae = function(arg0) {
-        const ret = getObject(arg0).pressed;
+        const ret = arg0.pressed;
         return ret;
     };
     imports.wbg.__wbg_pressure_8707a47b6fb1c1fd = function(arg0) {
-        const ret = getObject(arg0).pressure;
+        const ret = arg0.pressure;
         return ret;
     };
     imports.wbg.__wbg_preventDefault_faafffcaad92972d = function(arg0) {
-        getObject(arg0).preventDefault();
+        arg0.preventDefault();
     };
     imports.wbg.__wbg_process_5c1d670bc53614b8 = function(arg0) {
-        const ret = getObject(arg0).process;
-        return addHeapObject(ret);
+        const ret = arg0.process;
+        return ret;
     };
     imports.wbg.__wbg_prototype_0f68911d93450df4 = function() {
         const ret = ResizeObserverEntry.prototype;
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbg_push_3e9ce81246ef1d1b = function(arg0, arg1) {
-        const ret = getObject(arg0).push(getObject(arg1));
+        const ret = arg0.push(arg1);
         return ret;
     };
     imports.wbg.__wbg_querySelector_473a7bd6647e7cbb = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).querySelector(getStringFromWasm0(arg1, arg2));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.querySelector(getStringFromWasm0(arg1, arg2));
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     }, arguments) };
     imports.wbg.__wbg_queueMicrotask_3d422e1ba49c2500 = function(arg0) {
-        const ret = getObject(arg0).queueMicrotask;
-        return addHeapObject(ret);
+        const ret = arg0.queueMicrotask;
+        return ret;
     };
     imports.wbg.__wbg_queueMicrotask_5fc3e400ac3c03f4 = function(arg0) {
-        queueMicrotask(takeObject(arg0));
+        queueMicrotask(arg0);
     };
     imports.wbg.__wbg_queueMicrotask_f301663ccadbb7d0 = function(arg0) {
-        queueMicrotask(getObject(arg0));
+        queueMicrotask(arg0);
     };
     imports.wbg.__wbg_randomFillSync_ab2cfe79ebbf2740 = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).randomFillSync(takeObject(arg1));
+        arg0.randomFillSync(arg1);
     }, arguments) };
     imports.wbg.__wbg_readBuffer_8fd1512f6bdfc8f0 = function(arg0, arg1) {
-        getObject(arg0).readBuffer(arg1 >>> 0);
+        arg0.readBuffer(arg1 >>> 0);
     };
     imports.wbg.__wbg_readPixels_48fc96a447cda9aa = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) {
-        getObject(arg0).readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, getObject(arg7));
+        arg0.readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, arg7);
     }, arguments) };
     imports.wbg.__wbg_readPixels_7b1022930a9026d1 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) {
-        getObject(arg0).readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, arg7);
+        arg0.readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, arg7);
     }, arguments) };
     imports.wbg.__wbg_readPixels_da6e94b84b4cfd41 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) {
-        getObject(arg0).readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, getObject(arg7));
+        arg0.readPixels(arg1, arg2, arg3, arg4, arg5 >>> 0, arg6 >>> 0, arg7);
     }, arguments) };
     imports.wbg.__wbg_removeEventListener_d14a328308e427ba = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        getObject(arg0).removeEventListener(getStringFromWasm0(arg1, arg2), getObject(arg3));
+        arg0.removeEventListener(getStringFromWasm0(arg1, arg2), arg3);
     }, arguments) };
     imports.wbg.__wbg_removeListener_aee72eb2e5fba09c = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).removeListener(getObject(arg1));
+        arg0.removeListener(arg1);
     }, arguments) };
     imports.wbg.__wbg_removeProperty_902c73a4430b3e54 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = getObject(arg1).removeProperty(getStringFromWasm0(arg2, arg3));
+        const ret = arg1.removeProperty(getStringFromWasm0(arg2, arg3));
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     }, arguments) };
     imports.wbg.__wbg_renderbufferStorageMultisample_abd1099fecb2e2a4 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).renderbufferStorageMultisample(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
+        arg0.renderbufferStorageMultisample(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_renderbufferStorage_56c61eb0bb2718a9 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).renderbufferStorage(arg1 >>> 0, arg2 >>> 0, arg3, arg4);
+        arg0.renderbufferStorage(arg1 >>> 0, arg2 >>> 0, arg3, arg4);
     };
     imports.wbg.__wbg_renderbufferStorage_aa4b58453d5aca0f = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).renderbufferStorage(arg1 >>> 0, arg2 >>> 0, arg3, arg4);
+        arg0.renderbufferStorage(arg1 >>> 0, arg2 >>> 0, arg3, arg4);
     };
     imports.wbg.__wbg_repeat_56fa20e30d00be95 = function(arg0) {
-        const ret = getObject(arg0).repeat;
+        const ret = arg0.repeat;
         return ret;
     };
     imports.wbg.__wbg_requestAnimationFrame_e8ca543d07df528e = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).requestAnimationFrame(getObject(arg1));
+        const ret = arg0.requestAnimationFrame(arg1);
         return ret;
     }, arguments) };
     imports.wbg.__wbg_requestFullscreen_e1ad8752a4e33f7e = function(arg0) {
-        const ret = getObject(arg0).requestFullscreen;
-        return addHeapObject(ret);
+        const ret = arg0.requestFullscreen;
+        return ret;
     };
     imports.wbg.__wbg_requestFullscreen_f727b1f250ebb10a = function(arg0) {
-        const ret = getObject(arg0).requestFullscreen();
-        return addHeapObject(ret);
+        const ret = arg0.requestFullscreen();
+        return ret;
     };
     imports.wbg.__wbg_requestIdleCallback_773c554fc8c5a310 = function(arg0) {
-        const ret = getObject(arg0).requestIdleCallback;
-        return addHeapObject(ret);
+        const ret = arg0.requestIdleCallback;
+        return ret;
     };
     imports.wbg.__wbg_requestIdleCallback_ba1f658c72e2140c = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).requestIdleCallback(getObject(arg1));
+        const ret = arg0.requestIdleCallback(arg1);
         return ret;
     }, arguments) };
     imports.wbg.__wbg_requestPointerLock_31517250636f5da3 = function(arg0) {
-        getObject(arg0).requestPointerLock();
+        arg0.requestPointerLock();
     };
     imports.wbg.__wbg_require_79b1e9274cde3c87 = function() { return handleError(function () {
         const ret = module.require;
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_resolve_6a311e8bb26423ab = function(arg0) {
-        const ret = Promise.resolve(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = Promise.resolve(arg0);
+        return ret;
     };
     imports.wbg.__wbg_resume_6a2293b5156648d3 = function() { return handleError(function (arg0) {
-        const ret = getObject(arg0).resume();
-        return addHeapObject(ret);
+        const ret = arg0.resume();
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_revokeObjectURL_3e4ad6d46a9a93f1 = function() { return handleError(function (arg0, arg1) {
         URL.revokeObjectURL(getStringFromWasm0(arg0, arg1));
     }, arguments) };
     imports.wbg.__wbg_samplerParameterf_a9708333dc0df03e = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).samplerParameterf(getObject(arg1), arg2 >>> 0, arg3);
+        arg0.samplerParameterf(arg1, arg2 >>> 0, arg3);
     };
     imports.wbg.__wbg_samplerParameteri_6011eca3eaa96ac6 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).samplerParameteri(getObject(arg1), arg2 >>> 0, arg3);
+        arg0.samplerParameteri(arg1, arg2 >>> 0, arg3);
     };
     imports.wbg.__wbg_scheduler_10edeee35a76c7c6 = function(arg0) {
-        const ret = getObject(arg0).scheduler;
-        return addHeapObject(ret);
+        const ret = arg0.scheduler;
+        return ret;
     };
     imports.wbg.__wbg_scheduler_6d71128f17fbca34 = function(arg0) {
-        const ret = getObject(arg0).scheduler;
-        return addHeapObject(ret);
+        const ret = arg0.scheduler;
+        return ret;
     };
     imports.wbg.__wbg_scissor_4c06926fa8af817c = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).scissor(arg1, arg2, arg3, arg4);
+        arg0.scissor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_scissor_608c4f610141e6df = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).scissor(arg1, arg2, arg3, arg4);
+        arg0.scissor(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_self_ac4343e4047b83cc = function() { return handleError(function () {
         const ret = self.self;
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_setAttribute_e5d83ecaf7f586d5 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).setAttribute(getStringFromWasm0(arg1, arg2), getStringFromWasm0(arg3, arg4));
+        arg0.setAttribute(getStringFromWasm0(arg1, arg2), getStringFromWasm0(arg3, arg4));
+    }, arguments) };
+    imports.wbg.__wbg_setItem_7a9a3aaeafde3c1f = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4) {
+        arg0.setItem(getStringFromWasm0(arg1, arg2), getStringFromWasm0(arg3, arg4));
     }, arguments) };
     imports.wbg.__wbg_setPointerCapture_6b89bc3d20c408af = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).setPointerCapture(arg1);
+        arg0.setPointerCapture(arg1);
     }, arguments) };
     imports.wbg.__wbg_setProperty_b11b0bad191551d1 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).setProperty(getStringFromWasm0(arg1, arg2), getStringFromWasm0(arg3, arg4));
+        arg0.setProperty(getStringFromWasm0(arg1, arg2), getStringFromWasm0(arg3, arg4));
     }, arguments) };
     imports.wbg.__wbg_setTimeout_11f3c7cad8433a4f = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).setTimeout(getObject(arg1), arg2);
+        const ret = arg0.setTimeout(arg1, arg2);
         return ret;
     }, arguments) };
     imports.wbg.__wbg_setTimeout_15ba883433c836ab = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).setTimeout(getObject(arg1));
+        const ret = arg0.setTimeout(arg1);
         return ret;
     }, arguments) };
     imports.wbg.__wbg_set_421385e996a16e02 = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = Reflect.set(getObject(arg0), getObject(arg1), getObject(arg2));
+        const ret = Reflect.set(arg0, arg1, arg2);
         return ret;
     }, arguments) };
     imports.wbg.__wbg_set_7b70226104a82921 = function(arg0, arg1, arg2) {
-        getObject(arg0).set(getObject(arg1), arg2 >>> 0);
+        arg0.set(arg1, arg2 >>> 0);
     };
     imports.wbg.__wbg_setbox_f664fc1447c0b2bb = function(arg0, arg1) {
-        getObject(arg0).box = __wbindgen_enum_ResizeObserverBoxOptions[arg1];
+        arg0.box = __wbindgen_enum_ResizeObserverBoxOptions[arg1];
     };
     imports.wbg.__wbg_setbuffer_f1a83346b928c758 = function(arg0, arg1) {
-        getObject(arg0).buffer = getObject(arg1);
+        arg0.buffer = arg1;
     };
     imports.wbg.__wbg_setchannelCount_d4540f84ec659b4f = function(arg0, arg1) {
-        getObject(arg0).channelCount = arg1 >>> 0;
+        arg0.channelCount = arg1 >>> 0;
     };
     imports.wbg.__wbg_setheight_4286b13b9186d39f = function(arg0, arg1) {
-        getObject(arg0).height = arg1 >>> 0;
+        arg0.height = arg1 >>> 0;
     };
     imports.wbg.__wbg_setheight_7632621fed149fd9 = function(arg0, arg1) {
-        getObject(arg0).height = arg1 >>> 0;
+        arg0.height = arg1 >>> 0;
     };
     imports.wbg.__wbg_setonended_064db9a7078e45f3 = function(arg0, arg1) {
-        getObject(arg0).onended = getObject(arg1);
+        arg0.onended = arg1;
     };
     imports.wbg.__wbg_setonmessage_1f177e6cba71d19d = function(arg0, arg1) {
-        getObject(arg0).onmessage = getObject(arg1);
+        arg0.onmessage = arg1;
     };
     imports.wbg.__wbg_setsamplerate_843b76470413bdc3 = function(arg0, arg1) {
-        getObject(arg0).sampleRate = arg1;
+        arg0.sampleRate = arg1;
     };
     imports.wbg.__wbg_settype_202db174d92fe493 = function(arg0, arg1, arg2) {
-        getObject(arg0).type = getStringFromWasm0(arg1, arg2);
+        arg0.type = getStringFromWasm0(arg1, arg2);
     };
     imports.wbg.__wbg_setwidth_5e43e6e177d3e2ec = function(arg0, arg1) {
-        getObject(arg0).width = arg1 >>> 0;
+        arg0.width = arg1 >>> 0;
     };
     imports.wbg.__wbg_setwidth_db46810857c0f6bd = function(arg0, arg1) {
-        getObject(arg0).width = arg1 >>> 0;
+        arg0.width = arg1 >>> 0;
     };
     imports.wbg.__wbg_shaderSource_7d9e91c6b9aaf864 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).shaderSource(getObject(arg1), getStringFromWasm0(arg2, arg3));
+        arg0.shaderSource(arg1, getStringFromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_shaderSource_b7db90958962e1f7 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).shaderSource(getObject(arg1), getStringFromWasm0(arg2, arg3));
+        arg0.shaderSource(arg1, getStringFromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_shiftKey_570898b1142a9898 = function(arg0) {
-        const ret = getObject(arg0).shiftKey;
+        const ret = arg0.shiftKey;
         return ret;
     };
     imports.wbg.__wbg_shiftKey_e90da27a3092777e = function(arg0) {
-        const ret = getObject(arg0).shiftKey;
+        const ret = arg0.shiftKey;
         return ret;
     };
     imports.wbg.__wbg_signal_fd2d6d0644f16ad8 = function(arg0) {
-        const ret = getObject(arg0).signal;
-        return addHeapObject(ret);
+        const ret = arg0.signal;
+        return ret;
     };
     imports.wbg.__wbg_stack_0ed75d68575b0f3c = function(arg0, arg1) {
-        const ret = getObject(arg1).stack;
+        const ret = arg1.stack;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
     imports.wbg.__wbg_start_36cd2a90ad906d25 = function() { return handleError(function (arg0, arg1) {
-        getObject(arg0).start(arg1);
+        arg0.start(arg1);
     }, arguments) };
     imports.wbg.__wbg_start_a00c28f297559e1d = function(arg0) {
-        getObject(arg0).start();
+        arg0.start();
     };
     imports.wbg.__wbg_status_5f9868b7ed8dd175 = function(arg0) {
-        const ret = getObject(arg0).status;
+        const ret = arg0.status;
         return ret;
     };
     imports.wbg.__wbg_stencilFuncSeparate_903776516b404f1e = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).stencilFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3, arg4 >>> 0);
+        arg0.stencilFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3, arg4 >>> 0);
     };
     imports.wbg.__wbg_stencilFuncSeparate_9987b0fe1f74adc3 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).stencilFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3, arg4 >>> 0);
+        arg0.stencilFuncSeparate(arg1 >>> 0, arg2 >>> 0, arg3, arg4 >>> 0);
     };
     imports.wbg.__wbg_stencilMaskSeparate_24a5bad679d4896c = function(arg0, arg1, arg2) {
-        getObject(arg0).stencilMaskSeparate(arg1 >>> 0, arg2 >>> 0);
+        arg0.stencilMaskSeparate(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_stencilMaskSeparate_5ade706794e0ff10 = function(arg0, arg1, arg2) {
-        getObject(arg0).stencilMaskSeparate(arg1 >>> 0, arg2 >>> 0);
+        arg0.stencilMaskSeparate(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_stencilMask_8ccbc71e58e71b93 = function(arg0, arg1) {
-        getObject(arg0).stencilMask(arg1 >>> 0);
+        arg0.stencilMask(arg1 >>> 0);
     };
     imports.wbg.__wbg_stencilMask_e03561d8e6b7171a = function(arg0, arg1) {
-        getObject(arg0).stencilMask(arg1 >>> 0);
+        arg0.stencilMask(arg1 >>> 0);
     };
     imports.wbg.__wbg_stencilOpSeparate_13e313403862a2bc = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).stencilOpSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
+        arg0.stencilOpSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
     };
     imports.wbg.__wbg_stencilOpSeparate_e03fe45a6a83cf8f = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).stencilOpSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
+        arg0.stencilOpSeparate(arg1 >>> 0, arg2 >>> 0, arg3 >>> 0, arg4 >>> 0);
     };
     imports.wbg.__wbg_stringify_f5476f15b5654a07 = function() { return handleError(function (arg0) {
-        const ret = JSON.stringify(getObject(arg0));
-        return addHeapObject(ret);
+        const ret = JSON.stringify(arg0);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_style_e7c4e0938a7565b2 = function(arg0) {
-        const ret = getObject(arg0).style;
-        return addHeapObject(ret);
+        const ret = arg0.style;
+        return ret;
     };
     imports.wbg.__wbg_subarray_b4e9772c34a7f5ba = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).subarray(arg1 >>> 0, arg2 >>> 0);
-        return addHeapObject(ret);
+        const ret = arg0.subarray(arg1 >>> 0, arg2 >>> 0);
+        return ret;
     };
     imports.wbg.__wbg_texImage2D_102612af3b3ea301 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texImage2D_38f7a3dc4dcf0183 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texImage3D_4828de4d80648b9d = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) {
-        getObject(arg0).texImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8 >>> 0, arg9 >>> 0, getObject(arg10));
+        arg0.texImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8 >>> 0, arg9 >>> 0, arg10);
     }, arguments) };
     imports.wbg.__wbg_texParameteri_2cc96bb59a67d4c2 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).texParameteri(arg1 >>> 0, arg2 >>> 0, arg3);
+        arg0.texParameteri(arg1 >>> 0, arg2 >>> 0, arg3);
     };
     imports.wbg.__wbg_texParameteri_8e4109b7fbd3b875 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).texParameteri(arg1 >>> 0, arg2 >>> 0, arg3);
+        arg0.texParameteri(arg1 >>> 0, arg2 >>> 0, arg3);
     };
     imports.wbg.__wbg_texStorage2D_728b9632dbd913e2 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).texStorage2D(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
+        arg0.texStorage2D(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_texStorage3D_04d10ef19e465ec2 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6) {
-        getObject(arg0).texStorage3D(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5, arg6);
+        arg0.texStorage3D(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5, arg6);
     };
     imports.wbg.__wbg_texSubImage2D_0c095c30c73e34c0 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage2D_3c9a9ceac3c27fe7 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage2D_7197aef4cdbc7d70 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage2D_746d9e75d2dd12d1 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage2D_ca1d7744b168aa02 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage2D_da8455e8da280cee = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, getObject(arg9));
+        arg0.texSubImage2D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7 >>> 0, arg8 >>> 0, arg9);
     }, arguments) };
     imports.wbg.__wbg_texSubImage3D_82336f6100e3ef24 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, getObject(arg11));
+        arg0.texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
     }, arguments) };
     imports.wbg.__wbg_texSubImage3D_9623fc609df925f5 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, getObject(arg11));
+        arg0.texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
     }, arguments) };
     imports.wbg.__wbg_texSubImage3D_9a8cb5676fdf0b50 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
+        arg0.texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
     }, arguments) };
     imports.wbg.__wbg_texSubImage3D_a18f6424082a9393 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, getObject(arg11));
+        arg0.texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
     }, arguments) };
     imports.wbg.__wbg_texSubImage3D_d127dad275801f75 = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) {
-        getObject(arg0).texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, getObject(arg11));
+        arg0.texSubImage3D(arg1 >>> 0, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9 >>> 0, arg10 >>> 0, arg11);
     }, arguments) };
     imports.wbg.__wbg_then_5c6469c1e1da9e59 = function(arg0, arg1) {
-        const ret = getObject(arg0).then(getObject(arg1));
-        return addHeapObject(ret);
+        const ret = arg0.then(arg1);
+        return ret;
     };
     imports.wbg.__wbg_then_faeb8aed8c1629b7 = function(arg0, arg1, arg2) {
-        const ret = getObject(arg0).then(getObject(arg1), getObject(arg2));
-        return addHeapObject(ret);
+        const ret = arg0.then(arg1, arg2);
+        return ret;
     };
     imports.wbg.__wbg_uniform1f_163e6ffe0d9805a4 = function(arg0, arg1, arg2) {
-        getObject(arg0).uniform1f(getObject(arg1), arg2);
+        arg0.uniform1f(arg1, arg2);
     };
     imports.wbg.__wbg_uniform1f_bb85eb8ed9248e52 = function(arg0, arg1, arg2) {
-        getObject(arg0).uniform1f(getObject(arg1), arg2);
+        arg0.uniform1f(arg1, arg2);
     };
     imports.wbg.__wbg_uniform1i_9fe01b91ff85aa85 = function(arg0, arg1, arg2) {
-        getObject(arg0).uniform1i(getObject(arg1), arg2);
+        arg0.uniform1i(arg1, arg2);
     };
     imports.wbg.__wbg_uniform1i_da7c764279d55bb5 = function(arg0, arg1, arg2) {
-        getObject(arg0).uniform1i(getObject(arg1), arg2);
+        arg0.uniform1i(arg1, arg2);
     };
     imports.wbg.__wbg_uniform1ui_1cd8b5fee3b44787 = function(arg0, arg1, arg2) {
-        getObject(arg0).uniform1ui(getObject(arg1), arg2 >>> 0);
+        arg0.uniform1ui(arg1, arg2 >>> 0);
     };
     imports.wbg.__wbg_uniform2fv_40e0cc0b3d7ab815 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform2fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform2fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform2fv_d7ec4f77d2282f77 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform2fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform2fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform2iv_6d1ed66fa019f1a6 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform2iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform2iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform2iv_b36969471f3bb8e2 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform2iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform2iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform2uiv_e5d292b09c7c2eb4 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform2uiv(getObject(arg1), getArrayU32FromWasm0(arg2, arg3));
+        arg0.uniform2uiv(arg1, getArrayU32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform3fv_4f40a7b75bcdeb91 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform3fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform3fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform3fv_c6fea7086ffa568e = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform3fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform3fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform3iv_46cafa9a87333139 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform3iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform3iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform3iv_c13167af320af60e = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform3iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform3iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform3uiv_7e60873d7a35f50b = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform3uiv(getObject(arg1), getArrayU32FromWasm0(arg2, arg3));
+        arg0.uniform3uiv(arg1, getArrayU32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform4f_2408c0a3b4a0941d = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).uniform4f(getObject(arg1), arg2, arg3, arg4, arg5);
+        arg0.uniform4f(arg1, arg2, arg3, arg4, arg5);
     };
     imports.wbg.__wbg_uniform4f_4eebf85a05c60a16 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).uniform4f(getObject(arg1), arg2, arg3, arg4, arg5);
+        arg0.uniform4f(arg1, arg2, arg3, arg4, arg5);
     };
     imports.wbg.__wbg_uniform4fv_7ecf41f27e0e71ec = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform4fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform4fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform4fv_c88b1733f9a40a7a = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform4fv(getObject(arg1), getArrayF32FromWasm0(arg2, arg3));
+        arg0.uniform4fv(arg1, getArrayF32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform4iv_0ee5d566bd3880e4 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform4iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform4iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform4iv_cd69e9451d767045 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform4iv(getObject(arg1), getArrayI32FromWasm0(arg2, arg3));
+        arg0.uniform4iv(arg1, getArrayI32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniform4uiv_c55d0b6c672eaf59 = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniform4uiv(getObject(arg1), getArrayU32FromWasm0(arg2, arg3));
+        arg0.uniform4uiv(arg1, getArrayU32FromWasm0(arg2, arg3));
     };
     imports.wbg.__wbg_uniformBlockBinding_e646e98e7f5da8fa = function(arg0, arg1, arg2, arg3) {
-        getObject(arg0).uniformBlockBinding(getObject(arg1), arg2 >>> 0, arg3 >>> 0);
+        arg0.uniformBlockBinding(arg1, arg2 >>> 0, arg3 >>> 0);
     };
     imports.wbg.__wbg_uniformMatrix2fv_ae2d3b42477e00fd = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix2fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix2fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix2fv_d44126fac416e7e4 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix2fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix2fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix2x3fv_18d94e4451a372cc = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix2x3fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix2x3fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix2x4fv_c34a8a330e7c94bd = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix2x4fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix2x4fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix3fv_652493ea4042e14f = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix3fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix3fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix3fv_a3b27e44f0ad7caa = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix3fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix3fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix3x2fv_1bc7685c610e2b46 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix3x2fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix3x2fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix3x4fv_c99cdce05d7ffca9 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix3x4fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix3x4fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix4fv_a5f6838f6e217418 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix4fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix4fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix4fv_bbdf3d84ccedee84 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix4fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix4fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix4x2fv_05ec6db042145650 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix4x2fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix4x2fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_uniformMatrix4x3fv_c393892235ce0f8b = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).uniformMatrix4x3fv(getObject(arg1), arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
+        arg0.uniformMatrix4x3fv(arg1, arg2 !== 0, getArrayF32FromWasm0(arg3, arg4));
     };
     imports.wbg.__wbg_unobserve_9198337c0c96042f = function(arg0, arg1) {
-        getObject(arg0).unobserve(getObject(arg1));
+        arg0.unobserve(arg1);
     };
     imports.wbg.__wbg_useProgram_795e70e5047fcb65 = function(arg0, arg1) {
-        getObject(arg0).useProgram(getObject(arg1));
+        arg0.useProgram(arg1);
     };
     imports.wbg.__wbg_useProgram_e84b53bf74bbe9b3 = function(arg0, arg1) {
-        getObject(arg0).useProgram(getObject(arg1));
+        arg0.useProgram(arg1);
     };
     imports.wbg.__wbg_userAgentData_5e600df9bb352050 = function(arg0) {
-        const ret = getObject(arg0).userAgentData;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.userAgentData;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_userAgent_bfd54e5c60738678 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg1).userAgent;
+        const ret = arg1.userAgent;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     }, arguments) };
     imports.wbg.__wbg_value_b5aa6b1adcd4fcc2 = function(arg0) {
-        const ret = getObject(arg0).value;
+        const ret = arg0.value;
         return ret;
     };
     imports.wbg.__wbg_versions_c71aa1626a93e0a1 = function(arg0) {
-        const ret = getObject(arg0).versions;
-        return addHeapObject(ret);
+        const ret = arg0.versions;
+        return ret;
     };
     imports.wbg.__wbg_vertexAttribDivisorANGLE_78ad959056e0feb7 = function(arg0, arg1, arg2) {
-        getObject(arg0).vertexAttribDivisorANGLE(arg1 >>> 0, arg2 >>> 0);
+        arg0.vertexAttribDivisorANGLE(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_vertexAttribDivisor_bc3026299e814316 = function(arg0, arg1, arg2) {
-        getObject(arg0).vertexAttribDivisor(arg1 >>> 0, arg2 >>> 0);
+        arg0.vertexAttribDivisor(arg1 >>> 0, arg2 >>> 0);
     };
     imports.wbg.__wbg_vertexAttribIPointer_4397ca04b895d127 = function(arg0, arg1, arg2, arg3, arg4, arg5) {
-        getObject(arg0).vertexAttribIPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
+        arg0.vertexAttribIPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4, arg5);
     };
     imports.wbg.__wbg_vertexAttribPointer_1738c34c1c0d57a0 = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6) {
-        getObject(arg0).vertexAttribPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4 !== 0, arg5, arg6);
+        arg0.vertexAttribPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4 !== 0, arg5, arg6);
     };
     imports.wbg.__wbg_vertexAttribPointer_34a2b143ee35746f = function(arg0, arg1, arg2, arg3, arg4, arg5, arg6) {
-        getObject(arg0).vertexAttribPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4 !== 0, arg5, arg6);
+        arg0.vertexAttribPointer(arg1 >>> 0, arg2, arg3 >>> 0, arg4 !== 0, arg5, arg6);
     };
     imports.wbg.__wbg_videoHeight_7d8449d4b1aad15d = function(arg0) {
-        const ret = getObject(arg0).videoHeight;
+        const ret = arg0.videoHeight;
         return ret;
     };
     imports.wbg.__wbg_videoWidth_134d623b7b20f203 = function(arg0) {
-        const ret = getObject(arg0).videoWidth;
+        const ret = arg0.videoWidth;
         return ret;
     };
     imports.wbg.__wbg_viewport_04c48fc077486d94 = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).viewport(arg1, arg2, arg3, arg4);
+        arg0.viewport(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_viewport_301bba26f65246ed = function(arg0, arg1, arg2, arg3, arg4) {
-        getObject(arg0).viewport(arg1, arg2, arg3, arg4);
+        arg0.viewport(arg1, arg2, arg3, arg4);
     };
     imports.wbg.__wbg_visibilityState_c2b01655a6777e68 = function(arg0) {
-        const ret = getObject(arg0).visibilityState;
+        const ret = arg0.visibilityState;
         return (__wbindgen_enum_VisibilityState.indexOf(ret) + 1 || 3) - 1;
     };
     imports.wbg.__wbg_webkitExitFullscreen_da1f86a19cc44384 = function(arg0) {
-        getObject(arg0).webkitExitFullscreen();
+        arg0.webkitExitFullscreen();
     };
     imports.wbg.__wbg_webkitFullscreenElement_0c4654cdfe6894e5 = function(arg0) {
-        const ret = getObject(arg0).webkitFullscreenElement;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        const ret = arg0.webkitFullscreenElement;
+        return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
     };
     imports.wbg.__wbg_webkitRequestFullscreen_fe95241c4f21ea63 = function(arg0) {
-        getObject(arg0).webkitRequestFullscreen();
+        arg0.webkitRequestFullscreen();
     };
     imports.wbg.__wbg_width_514dd9b3b0cff49c = function(arg0) {
-        const ret = getObject(arg0).width;
+        const ret = arg0.width;
         return ret;
     };
     imports.wbg.__wbg_width_826b25a505a0b357 = function(arg0) {
-        const ret = getObject(arg0).width;
+        const ret = arg0.width;
         return ret;
     };
     imports.wbg.__wbg_width_dfc6149b0c4d8821 = function(arg0) {
-        const ret = getObject(arg0).width;
+        const ret = arg0.width;
         return ret;
     };
     imports.wbg.__wbg_width_ee6a46425045c0dd = function(arg0) {
-        const ret = getObject(arg0).width;
+        const ret = arg0.width;
         return ret;
     };
     imports.wbg.__wbg_window_1a23defd102c72f4 = function() { return handleError(function () {
         const ret = window.window;
-        return addHeapObject(ret);
+        return ret;
     }, arguments) };
     imports.wbg.__wbg_x_72ea8d07f989a4d5 = function(arg0) {
-        const ret = getObject(arg0).x;
+        const ret = arg0.x;
         return ret;
     };
     imports.wbg.__wbg_y_3a5a560bf09945d9 = function(arg0) {
-        const ret = getObject(arg0).y;
+        const ret = arg0.y;
         return ret;
     };
     imports.wbg.__wbindgen_boolean_get = function(arg0) {
-        const v = getObject(arg0);
+        const v = arg0;
         const ret = typeof(v) === 'boolean' ? (v ? 1 : 0) : 2;
         return ret;
     };
     imports.wbg.__wbindgen_cb_drop = function(arg0) {
-        const obj = takeObject(arg0).original;
+        const obj = arg0.original;
         if (obj.cnt-- == 1) {
             obj.a = 0;
             return true;
@@ -2061,101 +2198,108 @@ function __wbg_get_imports() {
         const ret = false;
         return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44218 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_34);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper121050 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 91335, __wbg_adapter_60);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44219 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper123597 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 91747, __wbg_adapter_63);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44223 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17965 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44228 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17966 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_39);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44229 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17967 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44230 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17968 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
+    };
+    imports.wbg.__wbindgen_closure_wrapper17969 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44238 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_48);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17970 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper44241 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17971 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper50583 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_34);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17972 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_36);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper51183 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper17973 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 12013, __wbg_adapter_54);
+        return ret;
     };
-    imports.wbg.__wbindgen_closure_wrapper54669 = function(arg0, arg1, arg2) {
-        const ret = makeMutClosure(arg0, arg1, 5382, __wbg_adapter_37);
-        return addHeapObject(ret);
+    imports.wbg.__wbindgen_closure_wrapper78384 = function(arg0, arg1, arg2) {
+        const ret = makeMutClosure(arg0, arg1, 59604, __wbg_adapter_57);
+        return ret;
     };
     imports.wbg.__wbindgen_debug_string = function(arg0, arg1) {
-        const ret = debugString(getObject(arg1));
+        const ret = debugString(arg1);
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
+    imports.wbg.__wbindgen_init_externref_table = function() {
+        const table = wasm.__wbindgen_export_1;
+        const offset = table.grow(4);
+        table.set(0, undefined);
+        table.set(offset + 0, undefined);
+        table.set(offset + 1, null);
+        table.set(offset + 2, true);
+        table.set(offset + 3, false);
+        ;
+    };
     imports.wbg.__wbindgen_is_function = function(arg0) {
-        const ret = typeof(getObject(arg0)) === 'function';
+        const ret = typeof(arg0) === 'function';
         return ret;
     };
     imports.wbg.__wbindgen_is_null = function(arg0) {
-        const ret = getObject(arg0) === null;
+        const ret = arg0 === null;
         return ret;
     };
     imports.wbg.__wbindgen_is_object = function(arg0) {
-        const val = getObject(arg0);
+        const val = arg0;
         const ret = typeof(val) === 'object' && val !== null;
         return ret;
     };
     imports.wbg.__wbindgen_is_string = function(arg0) {
-        const ret = typeof(getObject(arg0)) === 'string';
+        const ret = typeof(arg0) === 'string';
         return ret;
     };
     imports.wbg.__wbindgen_is_undefined = function(arg0) {
-        const ret = getObject(arg0) === undefined;
+        const ret = arg0 === undefined;
         return ret;
     };
     imports.wbg.__wbindgen_memory = function() {
         const ret = wasm.memory;
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbindgen_number_get = function(arg0, arg1) {
-        const obj = getObject(arg1);
+        const obj = arg1;
         const ret = typeof(obj) === 'number' ? obj : undefined;
         getDataViewMemory0().setFloat64(arg0 + 8 * 1, isLikeNone(ret) ? 0 : ret, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
     };
     imports.wbg.__wbindgen_number_new = function(arg0) {
         const ret = arg0;
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbindgen_object_clone_ref = function(arg0) {
-        const ret = getObject(arg0);
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbindgen_object_drop_ref = function(arg0) {
-        takeObject(arg0);
+        return ret;
     };
     imports.wbg.__wbindgen_string_get = function(arg0, arg1) {
-        const obj = getObject(arg1);
+        const obj = arg1;
         const ret = typeof(obj) === 'string' ? obj : undefined;
         var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
@@ -2164,7 +2308,7 @@ function __wbg_get_imports() {
     };
     imports.wbg.__wbindgen_string_new = function(arg0, arg1) {
         const ret = getStringFromWasm0(arg0, arg1);
-        return addHeapObject(ret);
+        return ret;
     };
     imports.wbg.__wbindgen_throw = function(arg0, arg1) {
         throw new Error(getStringFromWasm0(arg0, arg1));
@@ -1,27 +1,44 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export const main: (a: number, b: number) => number;
-export const wasm_main: () => void;
+export const get_daily_share_text: () => [number, number];
+export const get_high_score: () => number;
+export const get_next_ball: () => any;
+export const get_score: () => number;
+export const get_state: () => [number, number];
+export const get_upcoming_balls: () => any;
+export const on_game_over: (a: any) => void;
+export const on_merge: (a: any) => void;
+export const pause: () => void;
+export const restart: () => void;
+export const resume: () => void;
+export const set_setting: (a: number, b: number, c: any) => [number, number];
 export const settings_clicked: () => void;
+export const wasm_main: () => void;
+export const wgpu_render_bundle_draw: (a: number, b: number, c: number, d: number, e: number) => void;
+export const wgpu_render_bundle_draw_indexed: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
 export const wgpu_render_bundle_set_pipeline: (a: number, b: bigint) => void;
+export const wgpu_render_bundle_draw_indirect: (a: number, b: bigint, c: bigint) => void;
 export const wgpu_render_bundle_set_bind_group: (a: number, b: number, c: bigint, d: number, e: number) => void;
 export const wgpu_render_bundle_set_vertex_buffer: (a: number, b: number, c: bigint, d: bigint, e: bigint) => void;
 export const wgpu_render_bundle_set_push_constants: (a: number, b: number, c: number, d: number, e: number) => void;
-export const wgpu_render_bundle_draw: (a: number, b: number, c: number, d: number, e: number) => void;
-export const wgpu_render_bundle_draw_indexed: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const wgpu_render_bundle_draw_indirect: (a: number, b: bigint, c: bigint) => void;
 export const wgpu_render_bundle_draw_indexed_indirect: (a: number, b: bigint, c: bigint) => void;
-export const wgpu_render_bundle_set_index_buffer: (a: number, b: bigint, c: number, d: bigint, e: bigint) => void;
-export const wgpu_render_bundle_pop_debug_group: (a: number) => void;
 export const wgpu_render_bundle_insert_debug_marker: (a: number, b: number) => void;
+export const wgpu_render_bundle_pop_debug_group: (a: number) => void;
+export const wgpu_render_bundle_set_index_buffer: (a: number, b: bigint, c: number, d: bigint, e: bigint) => void;
 export const wgpu_render_bundle_push_debug_group: (a: number, b: number) => void;
+export const __externref_table_alloc: () => number;
+export const __wbindgen_export_1: WebAssembly.Table;
 export const __wbindgen_exn_store: (a: number) => void;
 export const __wbindgen_malloc: (a: number, b: number) => number;
 export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
 export const __wbindgen_free: (a: number, b: number, c: number) => void;
-export const __wbindgen_export_4: WebAssembly.Table;
-export const _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h9302db5f9e8a4b2e: (a: number, b: number) => void;
-export const _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h040d184f00a7eb90: (a: number, b: number, c: number) => void;
-export const _dyn_core__ops__function__FnMut__A_B___Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h0b351159393bee00: (a: number, b: number, c: number, d: number) => void;
+export const __wbindgen_export_6: WebAssembly.Table;
+export const __externref_table_dealloc: (a: number) => void;
+export const closure12012_externref_shim: (a: number, b: number, c: any) => void;
+export const _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h50414b3dc8a4e19b: (a: number, b: number) => void;
+export const closure12017_externref_shim: (a: number, b: number, c: any, d: any) => void;
+export const _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h059124e6db927739: (a: number, b: number) => void;
+export const _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h3fce110f8bd12cde: (a: number, b: number) => void;
+export const closure91746_externref_shim: (a: number, b: number, c: any) => void;
 export const __wbindgen_start: () => void;
@@ -1,34 +1,102 @@
 /* tslint:disable */
 /* eslint-disable */
+/**
+ * Sets one field of the settings by its saved name, e.g.
+ * `set_setting("volume", 0.3)` or `set_setting("sound_enabled", false)`.
+ * Numbers must be within the range of the settings menu's slider, and
+ * `visual_effects` ("Low", "Normal" or "High") applies that preset.
+ */
+export function set_setting(name: string, value: any): void;
+/**
+ * Clears the container and starts a fresh game.
+ */
+export function restart(): void;
+/**
+ * The latest official Daily result as text ready to paste, with the date,
+ * score and how far up the chain it got. Undefined until one has finished.
+ */
+export function get_daily_share_text(): string | undefined;
+/**
+ * The current score.
+ */
+export function get_score(): number;
+/**
+ * The game state: "MainMenu", "Playing", "Paused", "GameOver", "Win" or "Settings".
+ */
+export function get_state(): string;
+/**
+ * The ball that drops next as `{ index, name, sprite }`, or null before the
+ * game has started and once a puzzle has no balls left.
+ */
+export function get_next_ball(): any;
+/**
+ * Calls `callback({ won, mode, score })` when a game ends.
+ */
+export function on_game_over(callback: Function): void;
+/**
+ * The balls after the next one, soonest first, as many as the HUD shows.
+ */
+export function get_upcoming_balls(): any;
+/**
+ * Calls `callback({ from, into, points, combo, x, y })` after every merge.
+ */
+export function on_merge(callback: Function): void;
+/**
+ * Pauses a game in progress until `resume()`.
+ */
+export function pause(): void;
+/**
+ * The best score, including previous sessions.
+ */
+export function get_high_score(): number;
+export function resume(): void;
 export function wasm_main(): void;
+export function settings_clicked(): void;
 
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
   readonly memory: WebAssembly.Memory;
+  readonly get_daily_share_text: () => [number, number];
+  readonly get_high_score: () => number;
+  readonly get_next_ball: () => any;
+  readonly get_score: () => number;
+  readonly get_state: () => [number, number];
+  readonly get_upcoming_balls: () => any;
+  readonly on_game_over: (a: any) => void;
+  readonly on_merge: (a: any) => void;
+  readonly pause: () => void;
+  readonly restart: () => void;
+  readonly resume: () => void;
+  readonly set_setting: (a: number, b: number, c: any) => [number, number];
+  readonly settings_clicked: () => void;
   readonly wasm_main: () => void;
+  readonly wgpu_render_bundle_draw: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly wgpu_render_bundle_draw_indexed: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
   readonly wgpu_render_bundle_set_pipeline: (a: number, b: bigint) => void;
+  readonly wgpu_render_bundle_draw_indirect: (a: number, b: bigint, c: bigint) => void;
   readonly wgpu_render_bundle_set_bind_group: (a: number, b: number, c: bigint, d: number, e: number) => void;
   readonly wgpu_render_bundle_set_vertex_buffer: (a: number, b: number, c: bigint, d: bigint, e: bigint) => void;
   readonly wgpu_render_bundle_set_push_constants: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly wgpu_render_bundle_draw: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly wgpu_render_bundle_draw_indexed: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-  readonly wgpu_render_bundle_draw_indirect: (a: number, b: bigint, c: bigint) => void;
   readonly wgpu_render_bundle_draw_indexed_indirect: (a: number, b: bigint, c: bigint) => void;
-  readonly wgpu_render_bundle_set_index_buffer: (a: number, b: bigint, c: number, d: bigint, e: bigint) => void;
-  readonly wgpu_render_bundle_pop_debug_group: (a: number) => void;
   readonly wgpu_render_bundle_insert_debug_marker: (a: number, b: number) => void;
+  readonly wgpu_render_bundle_pop_debug_group: (a: number) => void;
+  readonly wgpu_render_bundle_set_index_buffer: (a: number, b: bigint, c: number, d: bigint, e: bigint) => void;
   readonly wgpu_render_bundle_push_debug_group: (a: number, b: number) => void;
+  readonly __externref_table_alloc: () => number;
+  readonly __wbindgen_export_1: WebAssembly.Table;
   readonly __wbindgen_exn_store: (a: number) => void;
   readonly __wbindgen_malloc: (a: number, b: number) => number;
   readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
   readonly __wbindgen_free: (a: number, b: number, c: number) => void;
-  readonly __wbindgen_export_4: WebAssembly.Table;
-  readonly _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h0c2982553b67613e: (a: number, b: number, c: number) => void;
-  readonly _dyn_core__ops__function__FnMut__A_B___Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h0b351159393bee00: (a: number, b: number, c: number, d: number) => void;
-  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__hdaacbe0609476dc7: (a: number, b: number) => void;
-  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h9302db5f9e8a4b2e: (a: number, b: number) => void;
-  readonly _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h040d184f00a7eb90: (a: number, b: number, c: number) => void;
+  readonly __wbindgen_export_6: WebAssembly.Table;
+  readonly __externref_table_dealloc: (a: number) => void;
+  readonly closure12012_externref_shim: (a: number, b: number, c: any) => void;
+  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h50414b3dc8a4e19b: (a: number, b: number) => void;
+  readonly closure12017_externref_shim: (a: number, b: number, c: any, d: any) => void;
+  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h059124e6db927739: (a: number, b: number) => void;
+  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h3fce110f8bd12cde: (a: number, b: number) => void;
+  readonly closure91746_externref_shim: (a: number, b: number, c: any) => void;
   readonly __wbindgen_start: () => void;
 }
 
@@ -0,0 +1,283 @@
+use bevy::{input::touch::Touches, prelude::*};
+
+use crate::input_map::{Action, Actions, BindingDevice};
+use crate::{
+    drop_ball, BallChain, BallPreview, DropBall, GameCamera, GameMode, GameState, HoldBall, Settings,
+};
+
+// Horizontal aim speeds in pixels per second
+const AIM_START_SPEED: f32 = 150.0;
+const AIM_MAX_SPEED: f32 = 600.0;
+const AIM_ACCELERATION: f32 = 1500.0;
+
+// Stick travel ignored around the centre
+const STICK_DEADZONE: f32 = 0.2;
+
+// Inside the container walls
+const AIM_LIMIT: f32 = 240.0;
+
+// A mouse release this soon after a touch release is the browser's emulated
+// click for the same tap
+const EMULATED_CLICK_WINDOW: f32 = 0.5;
+
+// Preview tint while waiting to drop, and once ready
+const COOLING_PREVIEW_COLOR: Color = Color::srgba(0.3, 0.3, 0.3, 0.3);
+const READY_PREVIEW_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.5);
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum AimDevice {
+    Pointer,
+    Keyboard,
+    Gamepad,
+}
+
+/// Where the next ball will drop. Whichever device was used last (pointer,
+/// keyboard or gamepad) moves it.
+#[derive(Resource)]
+struct Aim {
+    x: f32,
+    velocity: f32,
+    device: AimDevice,
+    visible: bool,
+    // The pointer went down on a HUD button, so letting go isn't a drop
+    pressed_on_ui: bool,
+}
+
+impl Default for Aim {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            velocity: 0.0,
+            device: AimDevice::Pointer,
+            visible: false,
+            pressed_on_ui: false,
+        }
+    }
+}
+
+/// Stops player input from dropping balls faster than the current mode's drop
+/// cooldown setting.
+#[derive(Resource, Default)]
+struct DropCooldown {
+    remaining: f32,
+    last_touch_release: Option<f32>,
+}
+
+pub(crate) struct AimPlugin;
+
+impl Plugin for AimPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Aim>()
+            .init_resource::<DropCooldown>()
+            .add_systems(Update, (
+                cool_down_drops,
+                pointer_aim,
+                axis_aim,
+                drop_input.before(drop_ball),
+                hold_input,
+                update_preview,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn cool_down_drops(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut cooldown: ResMut<DropCooldown>,
+) {
+    // Lowering the setting mid-wait takes effect straight away
+    cooldown.remaining = (cooldown.remaining - time.delta_seconds()).clamp(0.0, settings.drop_cooldown_for(*mode));
+}
+
+fn pointer_to_world(
+    position: Vec2,
+    camera_q: &Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+) -> Option<Vec2> {
+    let (camera, camera_transform) = camera_q.get_single().ok()?;
+    camera.viewport_to_world(camera_transform, position)
+        .map(|ray| ray.origin.truncate())
+}
+
+fn pointer_aim(
+    mut aim: ResMut<Aim>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    windows: Query<&Window>,
+) {
+    let touch = touches.iter().next().map(|touch| touch.position());
+    let moved = cursor_moved.read().count() > 0;
+    if touch.is_some() || moved || mouse.just_pressed(MouseButton::Left) {
+        aim.device = AimDevice::Pointer;
+    }
+    if aim.device != AimDevice::Pointer {
+        return;
+    }
+
+    // Get position from either touch or mouse
+    let position = touch.or_else(|| windows.get_single().ok().and_then(|window| window.cursor_position()));
+    match position.and_then(|position| pointer_to_world(position, &camera_q)) {
+        Some(world_position) => {
+            aim.x = world_position.x;
+            aim.visible = true;
+        }
+        None => aim.visible = false,
+    }
+}
+
+// The move actions and the left stick, speeding up while held
+fn axis_aim(
+    time: Res<Time>,
+    mut aim: ResMut<Aim>,
+    actions: Actions,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+) {
+    let direction = |device| {
+        actions.pressed_on(Action::MoveRight, device) as i32 as f32
+            - actions.pressed_on(Action::MoveLeft, device) as i32 as f32
+    };
+    let keys = direction(BindingDevice::Keyboard);
+    let buttons = direction(BindingDevice::Gamepad);
+
+    let mut input = 0.0;
+    let mut device = None;
+    if keys != 0.0 {
+        input = keys;
+        device = Some(AimDevice::Keyboard);
+    }
+    let stick = gamepads
+        .iter()
+        .filter_map(|gamepad| axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX)))
+        .find(|stick| stick.abs() > STICK_DEADZONE);
+    let pad = if buttons != 0.0 { Some(buttons) } else { stick };
+    if let Some(value) = pad {
+        input = value;
+        device = Some(AimDevice::Gamepad);
+    }
+
+    if let Some(device) = device {
+        aim.device = device;
+        aim.visible = true;
+    }
+    if aim.device == AimDevice::Pointer || input == 0.0 {
+        aim.velocity = 0.0;
+        return;
+    }
+
+    // Start at a gentle speed so taps make small moves, then ramp up
+    if aim.velocity == 0.0 || aim.velocity.signum() != input.signum() {
+        aim.velocity = input.signum() * AIM_START_SPEED;
+    }
+    let target = input * AIM_MAX_SPEED;
+    let max_change = AIM_ACCELERATION * time.delta_seconds();
+    aim.velocity += (target - aim.velocity).clamp(-max_change, max_change);
+    aim.x = (aim.x + aim.velocity * time.delta_seconds()).clamp(-AIM_LIMIT, AIM_LIMIT);
+}
+
+// At most one drop per frame, whichever devices fired
+#[allow(clippy::too_many_arguments)]
+fn drop_input(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut aim: ResMut<Aim>,
+    mut cooldown: ResMut<DropCooldown>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    actions: Actions,
+    camera_q: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    windows: Query<&Window>,
+    buttons: Query<&Interaction, With<Button>>,
+    mut drop_events: EventWriter<DropBall>,
+) {
+    if mouse.just_pressed(MouseButton::Left) || touches.any_just_pressed() {
+        aim.pressed_on_ui = buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    }
+
+    let now = time.elapsed_seconds();
+    let touch_released = touches.any_just_released();
+    if touch_released {
+        cooldown.last_touch_release = Some(now);
+    }
+    let emulated_click = cooldown
+        .last_touch_release
+        .is_some_and(|released| now - released < EMULATED_CLICK_WINDOW);
+    let mouse_released = mouse.just_released(MouseButton::Left) && !emulated_click;
+
+    let x = if touch_released || mouse_released {
+        if std::mem::take(&mut aim.pressed_on_ui) {
+            return;
+        }
+        // Drop where the pointer let go
+        let position = match touches.iter_just_released().next() {
+            Some(touch) => Some(touch.position()),
+            None => windows.get_single().ok().and_then(|window| window.cursor_position()),
+        };
+        let Some(world_position) = position.and_then(|position| pointer_to_world(position, &camera_q)) else {
+            return;
+        };
+        aim.device = AimDevice::Pointer;
+        world_position.x
+    } else if actions.just_pressed_on(Action::Drop, BindingDevice::Keyboard) {
+        aim.device = AimDevice::Keyboard;
+        aim.visible = true;
+        aim.x
+    } else if actions.just_pressed_on(Action::Drop, BindingDevice::Gamepad) {
+        aim.device = AimDevice::Gamepad;
+        aim.visible = true;
+        aim.x
+    } else {
+        return;
+    };
+
+    if cooldown.remaining > 0.0 {
+        return;
+    }
+    cooldown.remaining = settings.drop_cooldown_for(*mode);
+    drop_events.send(DropBall { x });
+}
+
+fn hold_input(actions: Actions, mut hold_events: EventWriter<HoldBall>) {
+    if actions.just_pressed(Action::Hold) {
+        hold_events.send(HoldBall);
+    }
+}
+
+fn update_preview(
+    aim: Res<Aim>,
+    cooldown: Res<DropCooldown>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut preview_query: Query<(&mut Transform, &mut Visibility, &mut Sprite, &BallPreview)>,
+    chain: Res<BallChain>,
+) {
+    let Ok((mut transform, mut visibility, mut sprite, preview)) = preview_query.get_single_mut() else {
+        return;
+    };
+
+    // Greyed out while cooling down, brightening as the next drop gets close
+    let duration = settings.drop_cooldown_for(*mode);
+    let waiting = if duration > 0.0 {
+        cooldown.remaining / duration
+    } else {
+        0.0
+    };
+    sprite.color = if waiting > 0.0 {
+        COOLING_PREVIEW_COLOR.mix(&READY_PREVIEW_COLOR, 0.5 * (1.0 - waiting))
+    } else {
+        READY_PREVIEW_COLOR
+    };
+
+    match preview.next_size {
+        Some(next_size) if aim.visible => {
+            transform.translation.x = aim.x;
+            // Position higher based on ball size to prevent clipping
+            transform.translation.y = 300.0 - (next_size.size(&chain) / 2.0) - 30.0;
+            *visibility = Visibility::Visible;
+        }
+        _ => *visibility = Visibility::Hidden,
+    }
+}
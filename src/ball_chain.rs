@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::ball_shape::BallShape;
+use crate::ron_asset::{RonAsset, RonAssetPlugin};
+
+// Base size for scaling all balls
+pub(crate) const BASE_BALL_SIZE: f32 = 45.0;
+
+/// One step of the evolution chain, as written in a `.chain.ron` file.
+#[derive(Deserialize, Clone, Debug)]
+pub struct BallTier {
+    pub name: String,
+    pub sprite: String,
+    /// Diameter as a multiple of the 45px base ball size
+    pub size_ratio: f32,
+    /// Relative odds of being dropped; 0 means the tier is only reachable by merging
+    #[serde(default)]
+    pub spawn_weight: f32,
+    /// Points for merging two balls of this tier
+    pub score: u32,
+    /// Name of the tier two of these combine into
+    #[serde(default)]
+    pub merges_into: Option<String>,
+    /// Sound played when a merge creates this tier, instead of the default collision sound
+    #[serde(default)]
+    pub sound: Option<String>,
+    /// Creating this tier wins the game
+    #[serde(default)]
+    pub wins_game: bool,
+    /// Collision shape; a circle unless the chain file says otherwise
+    #[serde(default)]
+    pub shape: BallShape,
+}
+
+/// The ordered list of ball tiers, smallest first. Lives both as an asset
+/// (so it can be hot-reloaded) and as the resource gameplay reads from.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+pub struct BallChain {
+    pub tiers: Vec<BallTier>,
+}
+
+impl Default for BallChain {
+    fn default() -> Self {
+        Self::built_in()
+    }
+}
+
+impl RonAsset for BallChain {
+    const PATH: &'static str = "emotions.chain.ron";
+    const EXTENSIONS: &'static [&'static str] = &["chain.ron"];
+    const BUILT_IN: &'static str = include_str!("../assets/emotions.chain.ron");
+    const NAME: &'static str = "ball chain";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.tiers.is_empty() {
+            return Err("chain has no tiers".to_string());
+        }
+        for (index, tier) in self.tiers.iter().enumerate() {
+            if tier.size_ratio <= 0.0 {
+                return Err(format!("tier {} has a non-positive size_ratio", tier.name));
+            }
+            if tier.spawn_weight < 0.0 {
+                return Err(format!("tier {} has a negative spawn_weight", tier.name));
+            }
+            if let Some(target) = &tier.merges_into {
+                match self.find(target) {
+                    None => return Err(format!("tier {} merges into unknown tier {}", tier.name, target)),
+                    // Otherwise two balls could merge back into themselves forever
+                    Some(variant) if variant.0 <= index => {
+                        return Err(format!("tier {} merges into {}, which is not a later tier", tier.name, target));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if self.tiers.iter().map(|tier| tier.spawn_weight).sum::<f32>() <= 0.0 {
+            return Err("no tier has a positive spawn_weight".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl BallChain {
+    /// Looks up a tier, falling back to the last one if a reload shortened the chain.
+    pub fn tier(&self, variant: BallVariant) -> &BallTier {
+        &self.tiers[variant.0.min(self.tiers.len() - 1)]
+    }
+
+    pub fn find(&self, name: &str) -> Option<BallVariant> {
+        self.tiers.iter().position(|tier| tier.name == name).map(BallVariant)
+    }
+}
+
+/// Index of a tier in the active `BallChain`, 0 being the smallest ball.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BallVariant(pub usize);
+
+impl BallVariant {
+    pub(crate) fn size(&self, chain: &BallChain) -> f32 {
+        BASE_BALL_SIZE * chain.tier(*self).size_ratio
+    }
+
+    pub(crate) fn sprite_path<'a>(&self, chain: &'a BallChain) -> &'a str {
+        &chain.tier(*self).sprite
+    }
+
+    pub(crate) fn next_variant(&self, chain: &BallChain) -> Option<Self> {
+        chain
+            .tier(*self)
+            .merges_into
+            .as_deref()
+            .and_then(|name| chain.find(name))
+    }
+
+    pub(crate) fn random(rng: &mut impl Rng, chain: &BallChain) -> Self {
+        // Calculate total weight
+        let total_weight: f32 = chain.tiers.iter().map(|tier| tier.spawn_weight).sum();
+
+        // Generate random value
+        let mut value = rng.gen::<f32>() * total_weight;
+
+        // Select variant based on weights
+        for (index, tier) in chain.tiers.iter().enumerate() {
+            if tier.spawn_weight <= 0.0 {
+                continue;
+            }
+            value -= tier.spawn_weight;
+            if value <= 0.0 {
+                return BallVariant(index);
+            }
+        }
+
+        // Fallback to smallest ball if something goes wrong
+        BallVariant(0)
+    }
+}
+
+/// Provides the `BallChain` resource, loaded from `assets/emotions.chain.ron`.
+pub(crate) struct BallChainPlugin;
+
+impl Plugin for BallChainPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<BallChain>::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_chain_is_valid() {
+        assert_eq!(BallChain::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn tiers_only_merge_forward() {
+        let mut chain = BallChain::default();
+        chain.tiers[2].merges_into = Some(chain.tiers[2].name.clone());
+        assert!(chain.validate().is_err());
+
+        chain.tiers[2].merges_into = Some(chain.tiers[0].name.clone());
+        assert!(chain.validate().is_err());
+    }
+
+    #[test]
+    fn negative_spawn_weights_are_rejected() {
+        let mut chain = BallChain::default();
+        chain.tiers[1].spawn_weight = -1.0;
+        assert!(chain.validate().is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use bevy::{prelude::*, render::render_resource::TextureFormat, utils::HashMap};
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+
+use crate::{Ball, BallChain};
+
+// Pixels with at least this alpha count as part of the ball
+const HULL_ALPHA_THRESHOLD: u8 = 128;
+
+// Colliders sit just inside the sprite so neighbours visually touch
+const COLLIDER_SCALE: f32 = 0.98;
+
+/// Physics shape for a tier, set with `shape:` in the chain file.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum BallShape {
+    #[default]
+    Circle,
+    /// Upright capsule as tall as the ball; `radius` is a fraction of its half-width
+    Capsule { radius: f32 },
+    /// Convex hull of the sprite's opaque pixels. Balls use a circle until the
+    /// sprite has loaded, and always in headless apps.
+    SpriteHull,
+}
+
+impl BallShape {
+    /// The collider a freshly spawned ball of `size` pixels gets.
+    pub(crate) fn collider(&self, size: f32) -> Collider {
+        let half_size = size * 0.5 * COLLIDER_SCALE;
+        match *self {
+            BallShape::Capsule { radius } => {
+                let radius = half_size * radius.clamp(0.05, 1.0);
+                Collider::capsule_y(half_size - radius, radius)
+            }
+            BallShape::Circle | BallShape::SpriteHull => Collider::ball(half_size),
+        }
+    }
+}
+
+/// Hull outlines keyed by sprite path, in unit space (the sprite spans -0.5..0.5).
+#[derive(Resource, Default)]
+struct SpriteHulls(HashMap<String, Vec<Vec2>>);
+
+// Marks balls that already have their sprite hull collider
+#[derive(Component)]
+struct SpriteHullCollider;
+
+/// Builds convex hull colliders from sprite alpha for tiers that ask for them.
+/// Needs an `AssetServer` and image assets, so it does nothing in headless apps.
+pub(crate) struct BallShapePlugin;
+
+impl Plugin for BallShapePlugin {
+    fn build(&self, app: &mut App) {
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_resource::<SpriteHulls>()
+                .add_systems(Update, (build_sprite_hulls, apply_sprite_hulls).chain());
+        }
+    }
+}
+
+fn build_sprite_hulls(
+    chain: Res<BallChain>,
+    asset_server: Res<AssetServer>,
+    images: Option<Res<Assets<Image>>>,
+    mut hulls: ResMut<SpriteHulls>,
+) {
+    let Some(images) = images else {
+        return;
+    };
+
+    for tier in &chain.tiers {
+        if tier.shape != BallShape::SpriteHull || hulls.0.contains_key(&tier.sprite) {
+            continue;
+        }
+        let Some(image) = asset_server
+            .get_handle::<Image>(&tier.sprite)
+            .and_then(|handle| images.get(&handle))
+        else {
+            continue;
+        };
+
+        match sprite_outline(image) {
+            Some(outline) => {
+                hulls.0.insert(tier.sprite.clone(), outline);
+            }
+            None => {
+                warn!("Can't build a collider hull from {}; using a circle", tier.sprite);
+                hulls.0.insert(tier.sprite.clone(), Vec::new());
+            }
+        }
+    }
+}
+
+fn apply_sprite_hulls(
+    mut commands: Commands,
+    chain: Res<BallChain>,
+    hulls: Res<SpriteHulls>,
+    balls: Query<(Entity, &Ball, &Sprite), Without<SpriteHullCollider>>,
+) {
+    for (entity, ball, sprite) in &balls {
+        let tier = chain.tier(ball.variant);
+        if tier.shape != BallShape::SpriteHull {
+            continue;
+        }
+        let Some(outline) = hulls.0.get(&tier.sprite) else {
+            continue;
+        };
+
+        let size = sprite.custom_size.unwrap_or(Vec2::ONE) * COLLIDER_SCALE;
+        let points: Vec<Vec2> = outline.iter().map(|point| *point * size).collect();
+        let mut entity_commands = commands.entity(entity);
+        if let Some(collider) = Collider::convex_hull(&points) {
+            entity_commands.insert(collider);
+        }
+        entity_commands.insert(SpriteHullCollider);
+    }
+}
+
+// The leftmost and rightmost opaque pixel of each row, which is all a convex
+// hull needs. Returns None for formats without an 8-bit alpha channel.
+fn sprite_outline(image: &Image) -> Option<Vec<Vec2>> {
+    if !matches!(
+        image.texture_descriptor.format,
+        TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb
+    ) {
+        return None;
+    }
+
+    let width = image.width() as usize;
+    let height = image.height() as usize;
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let to_unit = |x: usize, y: usize| {
+        Vec2::new(
+            (x as f32 + 0.5) / width as f32 - 0.5,
+            0.5 - (y as f32 + 0.5) / height as f32,
+        )
+    };
+
+    let mut outline = Vec::new();
+    for (y, row) in image.data.chunks_exact(width * 4).enumerate().take(height) {
+        let opaque = |pixel: &[u8]| pixel[3] >= HULL_ALPHA_THRESHOLD;
+        let first = row.chunks_exact(4).position(opaque);
+        let last = row.chunks_exact(4).rposition(opaque);
+        if let (Some(first), Some(last)) = (first, last) {
+            outline.push(to_unit(first, y));
+            outline.push(to_unit(last, y));
+        }
+    }
+
+    (outline.len() >= 3).then_some(outline)
+}
+
@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::widgets::spawn_top_hud;
+use crate::{
+    Action, GameEnded, GameMode, GameOutcome, GameOverText, GameState, InputMap, RestartGame, Score,
+};
+
+const BLITZ_DURATION: f32 = 120.0;
+
+// A merge this soon after the last one raises the multiplier, up to the max
+const FAST_MERGE_WINDOW: f32 = 2.0;
+const MAX_MERGE_MULTIPLIER: u32 = 3;
+
+/// Blitz's countdown and fast-merge multiplier. Idle in the other modes.
+#[derive(Resource)]
+pub struct BlitzClock {
+    /// Seconds left in the game
+    pub remaining: f32,
+    /// What the next fast merge would be multiplied by, once a streak starts
+    pub multiplier: u32,
+    /// The highest multiplier reached this game
+    pub best_multiplier: u32,
+    active: bool,
+    since_merge: f32,
+}
+
+impl Default for BlitzClock {
+    fn default() -> Self {
+        Self {
+            remaining: BLITZ_DURATION,
+            multiplier: 1,
+            best_multiplier: 1,
+            active: false,
+            since_merge: f32::INFINITY,
+        }
+    }
+}
+
+impl BlitzClock {
+    /// Points multiplier for a merge happening now. Always 1 outside Blitz.
+    pub(crate) fn merge_multiplier(&mut self) -> u32 {
+        if !self.active {
+            return 1;
+        }
+        self.multiplier = if self.since_merge <= FAST_MERGE_WINDOW {
+            (self.multiplier + 1).min(MAX_MERGE_MULTIPLIER)
+        } else {
+            1
+        };
+        self.best_multiplier = self.best_multiplier.max(self.multiplier);
+        self.since_merge = 0.0;
+        self.multiplier
+    }
+}
+
+#[derive(Component)]
+struct BlitzHud;
+
+/// Runs the Blitz clock and ends the game when it reaches zero.
+pub(crate) struct BlitzPlugin;
+
+impl Plugin for BlitzPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BlitzClock>()
+            .add_systems(Update, start_blitz)
+            .add_systems(Update, tick_blitz.run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// The countdown HUD and the results screen at time-out.
+pub(crate) struct BlitzHudPlugin;
+
+impl Plugin for BlitzHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_blitz_hud)
+            .add_systems(Update, update_blitz_hud)
+            .add_systems(OnEnter(GameState::GameOver), setup_blitz_results.run_if(is_blitz));
+    }
+}
+
+pub(crate) fn is_blitz(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Blitz
+}
+
+fn start_blitz(
+    mode: Res<GameMode>,
+    mut clock: ResMut<BlitzClock>,
+    mut restart_events: EventReader<RestartGame>,
+) {
+    if restart_events.read().count() > 0 {
+        *clock = BlitzClock {
+            active: *mode == GameMode::Blitz,
+            ..default()
+        };
+    }
+}
+
+fn tick_blitz(
+    time: Res<Time>,
+    score: Res<Score>,
+    mut clock: ResMut<BlitzClock>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut game_ended_events: EventWriter<GameEnded>,
+) {
+    if !clock.active || clock.remaining <= 0.0 {
+        return;
+    }
+
+    clock.since_merge += time.delta_seconds();
+    if clock.since_merge > FAST_MERGE_WINDOW && clock.multiplier != 1 {
+        clock.multiplier = 1;
+    }
+
+    clock.remaining = (clock.remaining - time.delta_seconds()).max(0.0);
+    if clock.remaining == 0.0 {
+        next_state.set(GameState::GameOver);
+        game_ended_events.send(GameEnded {
+            outcome: GameOutcome::TimeUp,
+            score: score.current,
+        });
+    }
+}
+
+fn setup_blitz_hud(mut commands: Commands) {
+    spawn_top_hud(&mut commands, 34.0, BlitzHud);
+}
+
+fn update_blitz_hud(
+    clock: Res<BlitzClock>,
+    state: Res<State<GameState>>,
+    mut hud: Query<(&mut Text, &mut Visibility), With<BlitzHud>>,
+) {
+    if !(clock.is_changed() || state.is_changed()) {
+        return;
+    }
+    let visible = clock.active && matches!(state.get(), GameState::Playing | GameState::Paused);
+    for (mut text, mut visibility) in &mut hud {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+        // Round up, so 0:00 only shows once time is really out
+        let seconds = clock.remaining.ceil() as u32;
+        let mut value = format!("{}:{:02}", seconds / 60, seconds % 60);
+        if clock.multiplier > 1 {
+            value += &format!("  x{}", clock.multiplier);
+        }
+        text.sections[0].value = value;
+        // Red for the last ten seconds
+        text.sections[0].style.color = if clock.remaining <= 10.0 {
+            Color::srgb(1.0, 0.3, 0.3)
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+// Replaces the usual game over text, and is cleaned up with it
+fn setup_blitz_results(
+    mut commands: Commands,
+    clock: Res<BlitzClock>,
+    score: Res<Score>,
+    map: Res<InputMap>,
+) {
+    let heading = if clock.remaining <= 0.0 { "Time's up!" } else { "Game Over!" };
+    let best = if score.current > 0 && score.current >= score.high_score {
+        "New Blitz best!".to_string()
+    } else {
+        format!("Blitz best: {}", score.high_score)
+    };
+    commands.spawn((
+        GameOverText,
+        TextBundle::from_section(
+            format!(
+                "{heading}\nScore: {}\n{best}\nTop multiplier: x{}\nPress {} to play again\nor {} for the menu",
+                score.current,
+                clock.best_multiplier,
+                map.hint(Action::Restart),
+                map.hint(Action::Menu),
+            ),
+            TextStyle {
+                font_size: 40.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            margin: UiRect::all(Val::Auto),
+            ..default()
+        }),
+    ));
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+
+use crate::{RestartGame, Stats};
+
+// How long a merged ball stays "hot" for a follow-up merge
+const DEFAULT_COMBO_WINDOW: f32 = 1.5;
+
+const COMBO_LABEL_LIFETIME: f32 = 1.0;
+const COMBO_LABEL_RISE_SPEED: f32 = 60.0;
+
+/// Tracks chain reactions: a merge that involves a ball made by another merge
+/// less than `window` seconds ago continues that ball's combo, and its score
+/// is multiplied by the combo count.
+#[derive(Resource)]
+pub struct ComboTracker {
+    /// The longest combo still able to continue, or 0
+    pub current: u32,
+    /// The longest combo this game
+    pub best: u32,
+    /// Seconds a merged ball can keep its combo going
+    pub window: f32,
+}
+
+impl Default for ComboTracker {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            best: 0,
+            window: DEFAULT_COMBO_WINDOW,
+        }
+    }
+}
+
+impl ComboTracker {
+    /// The combo for merging balls with these links, recorded as the latest.
+    pub(crate) fn record<'a>(&mut self, links: impl IntoIterator<Item = &'a ComboLink>) -> u32 {
+        let combo = links.into_iter().map(|link| link.combo).max().unwrap_or(0) + 1;
+        self.current = self.current.max(combo);
+        self.best = self.best.max(combo);
+        combo
+    }
+
+    pub(crate) fn link(&self, combo: u32) -> ComboLink {
+        ComboLink {
+            combo,
+            timer: Timer::from_seconds(self.window, TimerMode::Once),
+        }
+    }
+}
+
+/// On balls created by a merge, until the combo window runs out.
+#[derive(Component)]
+pub(crate) struct ComboLink {
+    combo: u32,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct ComboLabel {
+    timer: Timer,
+}
+
+pub(crate) struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ComboTracker>()
+            .add_systems(Update, (
+                expire_combo_links,
+                record_best_combo,
+                animate_combo_labels,
+                reset_combo,
+            ));
+    }
+}
+
+/// A floating "x3 COMBO" that rises and fades out from `position`.
+pub(crate) fn spawn_combo_label(commands: &mut Commands, position: Vec3, combo: u32) {
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(format!("x{combo} COMBO"), TextStyle {
+                font_size: 28.0 + 4.0 * combo.min(6) as f32,
+                color: Color::srgb(1.0, 0.85, 0.2),
+                ..default()
+            }),
+            transform: Transform::from_translation(position.truncate().extend(10.0)),
+            ..default()
+        },
+        ComboLabel {
+            timer: Timer::from_seconds(COMBO_LABEL_LIFETIME, TimerMode::Once),
+        },
+    ));
+}
+
+fn expire_combo_links(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut links: Query<(Entity, &mut ComboLink)>,
+    mut tracker: ResMut<ComboTracker>,
+) {
+    let mut current = 0;
+    for (entity, mut link) in &mut links {
+        if link.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<ComboLink>();
+        } else {
+            current = current.max(link.combo);
+        }
+    }
+    if tracker.current != current {
+        tracker.current = current;
+    }
+}
+
+fn record_best_combo(tracker: Res<ComboTracker>, mut stats: ResMut<Stats>) {
+    if tracker.is_changed() && tracker.best > stats.best_combo {
+        stats.best_combo = tracker.best;
+    }
+}
+
+fn animate_combo_labels(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut labels: Query<(Entity, &mut ComboLabel, &mut Transform, &mut Text)>,
+) {
+    for (entity, mut label, mut transform, mut text) in &mut labels {
+        if label.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.y += COMBO_LABEL_RISE_SPEED * time.delta_seconds();
+        let alpha = 1.0 - label.timer.fraction();
+        for section in &mut text.sections {
+            section.style.color.set_alpha(alpha);
+        }
+    }
+}
+
+fn reset_combo(
+    mut commands: Commands,
+    mut restart_events: EventReader<RestartGame>,
+    mut tracker: ResMut<ComboTracker>,
+    labels: Query<Entity, With<ComboLabel>>,
+) {
+    if restart_events.read().count() == 0 {
+        return;
+    }
+    tracker.current = 0;
+    tracker.best = 0;
+    for entity in &labels {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,254 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::widgets::spawn_results_line;
+use crate::{
+    restart_game, BallChain, BallDropped, BallVariant, BallsMerged, GameEnded, GameMode,
+    GameOverText, GameState, RestartGame, Score, Storage, WinText,
+};
+
+const DAILY_KEY: &str = "daily";
+
+// Mixed into the day number so the daily seeds don't line up with small
+// seeds picked for testing
+const DAILY_SEED_SALT: u64 = 0x6261_6c6c_6472_6f70;
+
+// One per tier reached, cycling for long chains, then one blank per tier left
+const CHAIN_EMOJI: [&str; 6] = ["🟣", "🔵", "🟢", "🟡", "🟠", "🔴"];
+const UNREACHED_EMOJI: &str = "⚪";
+
+/// Days since the Unix epoch, in UTC. Everyone playing on the same UTC date
+/// gets the same number.
+pub(crate) fn utc_day() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|since| since.as_millis() as f64)
+        .unwrap_or_default();
+    #[cfg(target_arch = "wasm32")]
+    let millis = js_sys::Date::now();
+    (millis / 86_400_000.0) as u64
+}
+
+/// The `GameRng` seed for a day's challenge.
+pub(crate) fn daily_seed(day: u64) -> u64 {
+    DAILY_SEED_SALT ^ day
+}
+
+/// `day` as YYYY-MM-DD.
+pub(crate) fn date_string(day: u64) -> String {
+    // Howard Hinnant's civil_from_days
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day_of_month:02}")
+}
+
+/// The latest official daily attempt, kept in `Storage`. Only the first Daily
+/// game of each UTC day counts; replays that day are practice.
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+pub struct DailyRecord {
+    pub day: Option<u64>,
+    pub score: u32,
+    /// Name of the biggest tier reached
+    pub highest: Option<String>,
+    /// Cleared while the attempt is still being played
+    pub finished: bool,
+}
+
+impl DailyRecord {
+    pub fn played_on(&self, day: u64) -> bool {
+        self.day == Some(day)
+    }
+
+    /// The result as plain text for pasting into a chat, or None before the
+    /// first finished attempt.
+    pub fn share_text(&self, chain: &BallChain) -> Option<String> {
+        let day = self.day.filter(|_| self.finished)?;
+        let highest = self.highest.as_deref().and_then(|name| chain.find(name));
+        let reached = highest.map_or(0, |variant| variant.0 + 1);
+        let mut text = format!("Ball Drop Daily {}\nScore: {}\n", date_string(day), self.score);
+        for index in 0..chain.tiers.len() {
+            text += if index < reached { CHAIN_EMOJI[index % CHAIN_EMOJI.len()] } else { UNREACHED_EMOJI };
+        }
+        if let Some(highest) = highest {
+            text += &format!(" {}", chain.tier(highest).name);
+        }
+        Some(text)
+    }
+}
+
+/// The Daily game in progress, if any.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct DailyRun {
+    /// Set for the length of a Daily game
+    pub day: Option<u64>,
+    /// Whether this game is the day's official attempt
+    pub official: bool,
+    pub highest: Option<BallVariant>,
+}
+
+/// Seeds Daily games from the date and keeps the `DailyRecord`.
+pub(crate) struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DailyRecord>()
+            .init_resource::<DailyRun>()
+            .add_systems(Startup, load_daily_record)
+            .add_systems(Update, (
+                start_daily_run.after(restart_game),
+                track_daily_run.run_if(in_state(GameState::Playing)),
+                finish_daily_run,
+            ).chain());
+    }
+}
+
+/// The results line under the game over and win overlays.
+pub(crate) struct DailyResultsPlugin;
+
+impl Plugin for DailyResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::GameOver), setup_daily_results::<GameOverText>.run_if(is_daily))
+            .add_systems(OnEnter(GameState::Win), setup_daily_results::<WinText>.run_if(is_daily));
+    }
+}
+
+pub(crate) fn is_daily(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Daily
+}
+
+fn load_daily_record(storage: Res<Storage>, mut record: ResMut<DailyRecord>) {
+    if let Some(saved) = storage.load::<DailyRecord>(DAILY_KEY) {
+        *record = saved;
+    }
+    // The page was closed or reloaded mid-attempt: it ends at what it scored
+    if record.day.is_some() && !record.finished {
+        record.finished = true;
+        storage.save(DAILY_KEY, &*record);
+    }
+}
+
+// restart_game has already picked the seed from the date; this decides
+// whether the new game counts
+fn start_daily_run(
+    mode: Res<GameMode>,
+    storage: Res<Storage>,
+    mut record: ResMut<DailyRecord>,
+    mut run: ResMut<DailyRun>,
+    mut restart_events: EventReader<RestartGame>,
+) {
+    if restart_events.read().count() == 0 {
+        return;
+    }
+
+    // Walking away from the official attempt still uses it up
+    if run.official && !record.finished {
+        record.finished = true;
+        storage.save(DAILY_KEY, &*record);
+    }
+
+    *run = DailyRun::default();
+    if *mode != GameMode::Daily {
+        return;
+    }
+    let day = utc_day();
+    run.day = Some(day);
+    run.official = !record.played_on(day);
+    if run.official {
+        // Saved straight away so reloading the page doesn't give a second try
+        *record = DailyRecord {
+            day: Some(day),
+            ..default()
+        };
+        storage.save(DAILY_KEY, &*record);
+    }
+}
+
+fn track_daily_run(
+    chain: Res<BallChain>,
+    storage: Res<Storage>,
+    score: Res<Score>,
+    mut run: ResMut<DailyRun>,
+    mut record: ResMut<DailyRecord>,
+    mut dropped_events: EventReader<BallDropped>,
+    mut merged_events: EventReader<BallsMerged>,
+) {
+    // Saved as it rises so an abandoned attempt keeps what it scored
+    let official = run.official && !record.finished;
+    if official && score.current > record.score {
+        record.score = score.current;
+        storage.save(DAILY_KEY, &*record);
+    }
+
+    let dropped = dropped_events.read().map(|dropped| dropped.variant);
+    let merged = merged_events.read().map(|merged| merged.into);
+    let Some(biggest) = dropped.chain(merged).max_by_key(|variant| variant.0) else {
+        return;
+    };
+    if run.day.is_none() || run.highest.is_some_and(|highest| highest.0 >= biggest.0) {
+        return;
+    }
+    run.highest = Some(biggest);
+    if official {
+        record.highest = Some(chain.tier(biggest).name.clone());
+        storage.save(DAILY_KEY, &*record);
+    }
+}
+
+fn finish_daily_run(
+    storage: Res<Storage>,
+    run: Res<DailyRun>,
+    mut record: ResMut<DailyRecord>,
+    mut game_ended_events: EventReader<GameEnded>,
+) {
+    for ended in game_ended_events.read() {
+        if run.official && !record.finished {
+            record.score = ended.score;
+            record.finished = true;
+            storage.save(DAILY_KEY, &*record);
+        }
+    }
+}
+
+fn setup_daily_results<Overlay: Component + Default>(
+    mut commands: Commands,
+    run: Res<DailyRun>,
+    record: Res<DailyRecord>,
+) {
+    let Some(day) = run.day else {
+        return;
+    };
+    let value = if run.official {
+        format!("Daily {}: official score saved\nA new set of balls tomorrow", date_string(day))
+    } else {
+        format!("Daily {}: practice run\nToday's official score: {}", date_string(day), record.score)
+    };
+    spawn_results_line(&mut commands, value, Overlay::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_follow_the_calendar() {
+        assert_eq!(date_string(0), "1970-01-01");
+        assert_eq!(date_string(11_017), "2000-03-01");
+        assert_eq!(date_string(19_723), "2024-01-01");
+        assert_eq!(date_string(19_782), "2024-02-29");
+    }
+
+    #[test]
+    fn each_day_has_its_own_seed() {
+        assert_eq!(daily_seed(19_723), daily_seed(19_723));
+        assert_ne!(daily_seed(19_723), daily_seed(19_724));
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{ball_texture, BallChain, BallVariant, GameState, Settings};
+
+const QUEUE_BALL_SIZE: f32 = 36.0;
+
+/// The balls after the one in the preview, soonest first. Rolled from
+/// `GameRng::queue` one at a time in drop order, so a fixed seed gives the
+/// same sequence however many are shown. Puzzle levels replace it with a
+/// fixed list that runs out.
+#[derive(Resource, Default)]
+pub struct DropQueue {
+    upcoming: VecDeque<BallVariant>,
+    fixed: bool,
+}
+
+impl DropQueue {
+    /// The next `count` balls, soonest first.
+    pub fn upcoming(&self, count: usize) -> impl Iterator<Item = BallVariant> + '_ {
+        self.upcoming.iter().copied().take(count)
+    }
+
+    /// Takes the next ball, keeping at least `length` more queued behind it.
+    /// None once a fixed queue has run out.
+    pub(crate) fn next(&mut self, rng: &mut impl Rng, chain: &BallChain, length: usize) -> Option<BallVariant> {
+        self.top_up(rng, chain, length + 1);
+        let next = self.upcoming.pop_front();
+        self.top_up(rng, chain, length);
+        next
+    }
+
+    /// Queues exactly `balls` and nothing after them, until the next `clear`.
+    pub(crate) fn set_fixed(&mut self, balls: impl IntoIterator<Item = BallVariant>) {
+        self.upcoming = balls.into_iter().collect();
+        self.fixed = true;
+    }
+
+    /// How many balls are left, if the queue is fixed.
+    pub fn remaining(&self) -> Option<usize> {
+        self.fixed.then_some(self.upcoming.len())
+    }
+
+    // Never trims, so shrinking the visible queue doesn't skip balls
+    fn top_up(&mut self, rng: &mut impl Rng, chain: &BallChain, length: usize) {
+        while !self.fixed && self.upcoming.len() < length {
+            self.upcoming.push_back(BallVariant::random(rng, chain));
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.upcoming.clear();
+        self.fixed = false;
+    }
+}
+
+#[derive(Component)]
+struct QueuePanel;
+
+/// The HUD panel listing the upcoming balls.
+pub(crate) struct QueuePanelPlugin;
+
+impl Plugin for QueuePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_queue_panel)
+            .add_systems(Update, update_queue_panel.run_if(
+                resource_changed::<DropQueue>.or_else(resource_changed::<Settings>),
+            ))
+            .add_systems(Update, show_queue_panel.run_if(state_changed::<GameState>));
+    }
+}
+
+fn setup_queue_panel(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.35)),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        QueuePanel,
+    ));
+}
+
+fn update_queue_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    chain: Res<BallChain>,
+    queue: Res<DropQueue>,
+    settings: Res<Settings>,
+    panels: Query<Entity, With<QueuePanel>>,
+) {
+    for panel in &panels {
+        commands.entity(panel)
+            .despawn_descendants()
+            .with_children(|panel| {
+                if settings.next_queue_length == 0 {
+                    return;
+                }
+                panel.spawn(TextBundle::from_section("Next", TextStyle {
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                    ..default()
+                }));
+                for variant in queue.upcoming(settings.next_queue_length) {
+                    panel.spawn(ImageBundle {
+                        image: UiImage::new(ball_texture(Some(&asset_server), &chain, variant)),
+                        style: Style {
+                            width: Val::Px(QUEUE_BALL_SIZE),
+                            height: Val::Px(QUEUE_BALL_SIZE),
+                            ..default()
+                        },
+                        ..default()
+                    });
+                }
+            });
+    }
+}
+
+// Only alongside a game in progress, and not over the menus
+fn show_queue_panel(
+    state: Res<State<GameState>>,
+    settings: Res<Settings>,
+    mut panels: Query<&mut Visibility, With<QueuePanel>>,
+) {
+    let visible = settings.next_queue_length > 0
+        && matches!(state.get(), GameState::Playing | GameState::Paused);
+    for mut visibility in &mut panels {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{RestartGame, Score, Stats};
+
+/// The rules the current game is played under. Set it before sending
+/// `RestartGame` to start a game in another mode.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Endless play until the danger zone fills up or the Win ball appears
+    #[default]
+    Classic,
+    /// Score as much as possible before the clock runs out
+    Blitz,
+    /// No game over: a full container pops its top layer instead, and effects
+    /// and sounds stay gentle
+    Zen,
+    /// Classic rules with a drop sequence seeded from the UTC date, the same
+    /// for every player. The first game each day is the official attempt
+    Daily,
+    /// Authored levels from `assets/`: a starting board, a fixed set of balls
+    /// and a goal to reach with them
+    Puzzle,
+    /// The floor keeps rising; the score is how long the player lasts
+    Survival,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 6] = [
+        GameMode::Classic,
+        GameMode::Blitz,
+        GameMode::Zen,
+        GameMode::Daily,
+        GameMode::Puzzle,
+        GameMode::Survival,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "Classic",
+            GameMode::Blitz => "Blitz",
+            GameMode::Zen => "Zen",
+            GameMode::Daily => "Daily",
+            GameMode::Puzzle => "Puzzle",
+            GameMode::Survival => "Survival",
+        }
+    }
+
+    pub(crate) fn description(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "merge your way up to the Win ball",
+            GameMode::Blitz => "two minutes, quick merges score extra",
+            GameMode::Zen => "no game over, just relaxed merging",
+            GameMode::Daily => "the same balls for everyone today, one official try",
+            GameMode::Puzzle => "set boards with a goal and a handful of balls",
+            GameMode::Survival => "the floor rises, last as long as you can",
+        }
+    }
+}
+
+/// Keeps `Score::high_score` pointing at the current mode's best.
+pub(crate) struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameMode>()
+            .add_systems(Update, switch_high_score);
+    }
+}
+
+fn switch_high_score(
+    mode: Res<GameMode>,
+    mut previous_mode: Local<GameMode>,
+    mut stats: ResMut<Stats>,
+    mut score: ResMut<Score>,
+    mut restart_events: EventReader<RestartGame>,
+) {
+    if restart_events.read().count() == 0 || *mode == *previous_mode {
+        return;
+    }
+    // Games abandoned from the pause menu never reach save_stats, so keep
+    // their best before switching
+    stats.record_score(*previous_mode, score.high_score);
+    score.high_score = stats.best_score(*mode);
+    *previous_mode = *mode;
+}
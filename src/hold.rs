@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::{
+    ball_texture, drop_ball, is_frozen, BallChain, BallDropped, BallPreview, BallVariant,
+    DropQueue, GameMode, GameRng, GameState, RestartGame, Settings,
+};
+
+const HOLD_BALL_SIZE: f32 = 40.0;
+
+const HOLD_BOX_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.35);
+// Already swapped since the last drop
+const HOLD_BOX_USED_COLOR: Color = Color::srgba(0.3, 0.0, 0.0, 0.35);
+
+/// Swaps the next ball with the held one. With nothing held yet, the next ball
+/// goes on hold and the queue moves along. Ignored if the player has already
+/// swapped since the last drop.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct HoldBall;
+
+/// The hold slot.
+#[derive(Resource, Default)]
+pub struct HeldBall {
+    pub variant: Option<BallVariant>,
+    /// Set by a swap and cleared by the next drop
+    pub used: bool,
+}
+
+pub(crate) struct HoldPlugin;
+
+impl Plugin for HoldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeldBall>()
+            .add_event::<HoldBall>()
+            .add_systems(Update, (reset_hold, swap_held).chain().after(drop_ball).run_if(not(is_frozen)));
+    }
+}
+
+#[derive(Component)]
+struct HoldBox;
+
+#[derive(Component)]
+struct HoldBoxImage;
+
+/// The HUD box showing the held ball. Tapping it swaps.
+pub(crate) struct HoldBoxPlugin;
+
+impl Plugin for HoldBoxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_hold_box)
+            .add_systems(Update, (
+                hold_box_interaction.run_if(in_state(GameState::Playing)),
+                update_hold_box.run_if(resource_changed::<HeldBall>),
+                show_hold_box.run_if(state_changed::<GameState>),
+            ));
+    }
+}
+
+fn reset_hold(
+    mut held: ResMut<HeldBall>,
+    mut dropped_events: EventReader<BallDropped>,
+    mut restart_events: EventReader<RestartGame>,
+) {
+    if restart_events.read().count() > 0 {
+        *held = HeldBall::default();
+    } else if dropped_events.read().count() > 0 && held.used {
+        held.used = false;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn swap_held(
+    mut hold_events: EventReader<HoldBall>,
+    mut held: ResMut<HeldBall>,
+    mut preview_query: Query<&mut BallPreview>,
+    mut queue: ResMut<DropQueue>,
+    mut rng: ResMut<GameRng>,
+    mode: Res<GameMode>,
+    settings: Res<Settings>,
+    chain: Res<BallChain>,
+) {
+    for _ in hold_events.read() {
+        // A puzzle's drops are fixed, so holding would only lose one
+        if held.used || *mode == GameMode::Puzzle {
+            continue;
+        }
+        let Ok(mut preview) = preview_query.get_single_mut() else {
+            continue;
+        };
+        let Some(current) = preview.next_size else {
+            continue;
+        };
+        let next = match held.variant {
+            Some(variant) => Some(variant),
+            None => queue.next(&mut rng.queue, &chain, settings.next_queue_length),
+        };
+        held.variant = Some(current);
+        held.used = true;
+        preview.next_size = next;
+    }
+}
+
+fn setup_hold_box(mut commands: Commands) {
+    commands
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(10.0),
+                    top: Val::Px(90.0),
+                    width: Val::Px(64.0),
+                    height: Val::Px(72.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(4.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(HOLD_BOX_COLOR),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HoldBox,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section("Hold", TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            }));
+            parent.spawn((
+                ImageBundle {
+                    style: Style {
+                        width: Val::Px(HOLD_BALL_SIZE),
+                        height: Val::Px(HOLD_BALL_SIZE),
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                HoldBoxImage,
+            ));
+        });
+}
+
+// The press never turns into a drop; see drop_input
+fn hold_box_interaction(
+    boxes: Query<&Interaction, (Changed<Interaction>, With<HoldBox>)>,
+    mut hold_events: EventWriter<HoldBall>,
+) {
+    if boxes.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        hold_events.send(HoldBall);
+    }
+}
+
+fn update_hold_box(
+    asset_server: Res<AssetServer>,
+    chain: Res<BallChain>,
+    held: Res<HeldBall>,
+    mut boxes: Query<&mut BackgroundColor, With<HoldBox>>,
+    mut images: Query<(&mut UiImage, &mut Visibility), With<HoldBoxImage>>,
+) {
+    for mut color in &mut boxes {
+        *color = BackgroundColor(if held.used { HOLD_BOX_USED_COLOR } else { HOLD_BOX_COLOR });
+    }
+    for (mut image, mut visibility) in &mut images {
+        match held.variant {
+            Some(variant) => {
+                image.texture = ball_texture(Some(&asset_server), &chain, variant);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn show_hold_box(
+    state: Res<State<GameState>>,
+    mode: Res<GameMode>,
+    mut boxes: Query<&mut Visibility, With<HoldBox>>,
+) {
+    let visible = *mode != GameMode::Puzzle && matches!(state.get(), GameState::Playing | GameState::Paused);
+    for mut visibility in &mut boxes {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::Storage;
+
+const INPUT_MAP_KEY: &str = "input_map";
+
+/// Something the player can do with a key or gamepad button.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    Drop,
+    Hold,
+    Restart,
+    Pause,
+    Settings,
+    /// Back to the main menu from the game over and win screens
+    Menu,
+}
+
+impl Action {
+    pub const ALL: [Action; 8] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Drop,
+        Action::Hold,
+        Action::Restart,
+        Action::Pause,
+        Action::Settings,
+        Action::Menu,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Action::MoveLeft => "Move left",
+            Action::MoveRight => "Move right",
+            Action::Drop => "Drop",
+            Action::Hold => "Hold",
+            Action::Restart => "Restart",
+            Action::Pause => "Pause",
+            Action::Settings => "Settings",
+            Action::Menu => "Menu",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Gamepad(GamepadButtonType),
+}
+
+impl Binding {
+    pub(crate) fn device(&self) -> BindingDevice {
+        match self {
+            Binding::Key(_) => BindingDevice::Keyboard,
+            Binding::Gamepad(_) => BindingDevice::Gamepad,
+        }
+    }
+
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Binding::Key(key) => {
+                let name = format!("{key:?}");
+                name.strip_prefix("Key").unwrap_or(&name).to_string()
+            }
+            Binding::Gamepad(button) => format!("{button:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum BindingDevice {
+    Keyboard,
+    Gamepad,
+}
+
+/// Which keys and gamepad buttons trigger each `Action`. Saved to `Storage`
+/// whenever it changes.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<Binding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        use Binding::{Gamepad, Key};
+        use GamepadButtonType as Pad;
+
+        Self {
+            bindings: HashMap::from([
+                (Action::MoveLeft, vec![Key(KeyCode::ArrowLeft), Key(KeyCode::KeyA), Gamepad(Pad::DPadLeft)]),
+                (Action::MoveRight, vec![Key(KeyCode::ArrowRight), Key(KeyCode::KeyD), Gamepad(Pad::DPadRight)]),
+                (Action::Drop, vec![Key(KeyCode::Space), Gamepad(Pad::South)]),
+                (Action::Hold, vec![Key(KeyCode::KeyC), Gamepad(Pad::West)]),
+                (Action::Restart, vec![Key(KeyCode::KeyR), Gamepad(Pad::Select)]),
+                (Action::Pause, vec![Key(KeyCode::Escape), Gamepad(Pad::Start)]),
+                (Action::Settings, vec![Key(KeyCode::KeyO)]),
+                (Action::Menu, vec![Key(KeyCode::KeyM), Gamepad(Pad::East)]),
+            ]),
+        }
+    }
+}
+
+impl InputMap {
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// What to tell the player to press for `action` in on-screen text: its
+    /// first key, or its first gamepad button if it has no key.
+    pub fn hint(&self, action: Action) -> String {
+        let bindings = self.bindings(action);
+        bindings
+            .iter()
+            .find(|binding| binding.device() == BindingDevice::Keyboard)
+            .or(bindings.first())
+            .map_or_else(|| "-".to_string(), |binding| binding.name().to_uppercase())
+    }
+
+    /// Makes `binding` the only one of its device for `action`, taking it away
+    /// from any other action.
+    pub fn rebind(&mut self, action: Action, binding: Binding) {
+        for bindings in self.bindings.values_mut() {
+            bindings.retain(|existing| *existing != binding);
+        }
+        let bindings = self.bindings.entry(action).or_default();
+        bindings.retain(|existing| existing.device() != binding.device());
+        bindings.push(binding);
+    }
+}
+
+/// Reads actions from the keyboard and every connected gamepad.
+#[derive(SystemParam)]
+pub(crate) struct Actions<'w> {
+    map: Res<'w, InputMap>,
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Res<'w, Gamepads>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+}
+
+impl Actions<'_> {
+    fn check(
+        &self,
+        action: Action,
+        device: BindingDevice,
+        key: impl Fn(&ButtonInput<KeyCode>, KeyCode) -> bool,
+        button: impl Fn(&ButtonInput<GamepadButton>, GamepadButton) -> bool,
+    ) -> bool {
+        self.map.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(code) if device == BindingDevice::Keyboard => key(&self.keyboard, code),
+            Binding::Gamepad(button_type) if device == BindingDevice::Gamepad => self
+                .gamepads
+                .iter()
+                .any(|gamepad| button(&self.gamepad_buttons, GamepadButton::new(gamepad, button_type))),
+            _ => false,
+        })
+    }
+
+    pub fn pressed_on(&self, action: Action, device: BindingDevice) -> bool {
+        self.check(action, device, |input, key| input.pressed(key), |input, button| input.pressed(button))
+    }
+
+    pub fn just_pressed_on(&self, action: Action, device: BindingDevice) -> bool {
+        self.check(action, device, |input, key| input.just_pressed(key), |input, button| input.just_pressed(button))
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed_on(action, BindingDevice::Keyboard) || self.just_pressed_on(action, BindingDevice::Gamepad)
+    }
+}
+
+pub(crate) struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputMap>()
+            .add_systems(PreStartup, load_input_map)
+            .add_systems(Last, save_input_map.run_if(resource_changed::<InputMap>));
+    }
+}
+
+fn load_input_map(storage: Res<Storage>, mut map: ResMut<InputMap>) {
+    if let Some(mut saved) = storage.load::<InputMap>(INPUT_MAP_KEY) {
+        // Actions added since the save get their default bindings
+        for (action, bindings) in InputMap::default().bindings {
+            saved.bindings.entry(action).or_insert(bindings);
+        }
+        *map = saved;
+    }
+}
+
+fn save_input_map(storage: Res<Storage>, map: Res<InputMap>) {
+    storage.save(INPUT_MAP_KEY, &*map);
+}
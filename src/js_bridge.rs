@@ -0,0 +1,315 @@
+use std::cell::RefCell;
+
+use bevy::prelude::*;
+use serde::Serialize;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::settings_menu::clamp_settings;
+use crate::{
+    BallChain, BallPreview, BallsMerged, DailyRecord, DropQueue, GameEnded, GameMode, GameOutcome, GameState,
+    RestartGame, Score, Settings,
+};
+
+// wasm is single threaded, so the exported functions and the systems below
+// share state through thread locals. Calls from the page are queued and
+// applied on the next frame.
+thread_local! {
+    static SNAPSHOT: RefCell<Snapshot> = RefCell::default();
+    static COMMANDS: RefCell<Vec<BridgeCommand>> = RefCell::default();
+    static MERGE_CALLBACKS: RefCell<Vec<js_sys::Function>> = RefCell::default();
+    static GAME_OVER_CALLBACKS: RefCell<Vec<js_sys::Function>> = RefCell::default();
+}
+
+#[derive(Default)]
+struct Snapshot {
+    score: u32,
+    high_score: u32,
+    state: String,
+    next_ball: Option<BallInfo>,
+    upcoming: Vec<BallInfo>,
+    daily_share_text: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct BallInfo {
+    index: usize,
+    name: String,
+    sprite: String,
+}
+
+enum BridgeCommand {
+    Pause,
+    Resume,
+    Restart,
+    SetSetting(String, Value),
+}
+
+#[derive(Serialize)]
+struct MergeInfo {
+    from: BallInfo,
+    into: BallInfo,
+    points: u32,
+    combo: u32,
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize)]
+struct GameOverInfo {
+    won: bool,
+    mode: &'static str,
+    score: u32,
+}
+
+/// Keeps the page-facing API below in sync with the app. Web builds only.
+pub(crate) struct JsBridgePlugin;
+
+impl Plugin for JsBridgePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (apply_bridge_commands, publish_snapshot, forward_events));
+    }
+}
+
+/// The current score.
+#[wasm_bindgen]
+pub fn get_score() -> u32 {
+    SNAPSHOT.with_borrow(|snapshot| snapshot.score)
+}
+
+/// The best score, including previous sessions.
+#[wasm_bindgen]
+pub fn get_high_score() -> u32 {
+    SNAPSHOT.with_borrow(|snapshot| snapshot.high_score)
+}
+
+/// The game state: "MainMenu", "Playing", "Paused", "GameOver", "Win" or "Settings".
+#[wasm_bindgen]
+pub fn get_state() -> String {
+    SNAPSHOT.with_borrow(|snapshot| snapshot.state.clone())
+}
+
+/// The ball that drops next as `{ index, name, sprite }`, or null before the
+/// game has started and once a puzzle has no balls left.
+#[wasm_bindgen]
+pub fn get_next_ball() -> JsValue {
+    SNAPSHOT.with_borrow(|snapshot| to_js(&snapshot.next_ball))
+}
+
+/// The balls after the next one, soonest first, as many as the HUD shows.
+#[wasm_bindgen]
+pub fn get_upcoming_balls() -> JsValue {
+    SNAPSHOT.with_borrow(|snapshot| to_js(&snapshot.upcoming))
+}
+
+/// The latest official Daily result as text ready to paste, with the date,
+/// score and how far up the chain it got. Undefined until one has finished.
+#[wasm_bindgen]
+pub fn get_daily_share_text() -> Option<String> {
+    SNAPSHOT.with_borrow(|snapshot| snapshot.daily_share_text.clone())
+}
+
+/// Calls `callback({ from, into, points, combo, x, y })` after every merge.
+#[wasm_bindgen]
+pub fn on_merge(callback: js_sys::Function) {
+    MERGE_CALLBACKS.with_borrow_mut(|callbacks| callbacks.push(callback));
+}
+
+/// Calls `callback({ won, mode, score })` when a game ends.
+#[wasm_bindgen]
+pub fn on_game_over(callback: js_sys::Function) {
+    GAME_OVER_CALLBACKS.with_borrow_mut(|callbacks| callbacks.push(callback));
+}
+
+/// Pauses a game in progress until `resume()`.
+#[wasm_bindgen]
+pub fn pause() {
+    queue(BridgeCommand::Pause);
+}
+
+#[wasm_bindgen]
+pub fn resume() {
+    queue(BridgeCommand::Resume);
+}
+
+/// Clears the container and starts a fresh game.
+#[wasm_bindgen]
+pub fn restart() {
+    queue(BridgeCommand::Restart);
+}
+
+/// Sets one field of the settings by its saved name, e.g.
+/// `set_setting("volume", 0.3)` or `set_setting("sound_enabled", false)`.
+/// Numbers must be within the range of the settings menu's slider, and
+/// `visual_effects` ("Low", "Normal" or "High") applies that preset.
+#[wasm_bindgen]
+pub fn set_setting(name: &str, value: JsValue) -> Result<(), JsValue> {
+    let known = serde_json::to_value(Settings::default())
+        .ok()
+        .and_then(|settings| settings.get(name).cloned())
+        .is_some();
+    if !known {
+        return Err(JsValue::from_str(&format!("unknown setting {name}")));
+    }
+
+    let value = if let Some(value) = value.as_bool() {
+        Value::Bool(value)
+    } else if let Some(value) = value.as_f64() {
+        // JS has only one number type; whole numbers go over as integers so
+        // integer settings accept them
+        let number = if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+            Some(serde_json::Number::from(value as i64))
+        } else {
+            serde_json::Number::from_f64(value)
+        };
+        match number {
+            Some(number) => Value::Number(number),
+            None => return Err(JsValue::from_str(&format!("unsupported value for {name}"))),
+        }
+    } else if let Some(value) = value.as_string() {
+        Value::String(value)
+    } else {
+        return Err(JsValue::from_str(&format!("unsupported value for {name}")));
+    };
+
+    // Try it on the defaults first, so a value of the wrong type or outside
+    // the menu's range fails here where the page can see it
+    let patched = patch_settings(&Settings::default(), name, value.clone())
+        .map_err(|err| JsValue::from_str(&format!("invalid value for {name}: {err}")))?;
+    let mut clamped = patched.clone();
+    clamp_settings(&mut clamped);
+    if clamped != patched {
+        return Err(JsValue::from_str(&format!("{name} is outside the range the settings menu allows")));
+    }
+    queue(BridgeCommand::SetSetting(name.to_string(), value));
+    Ok(())
+}
+
+fn patch_settings(settings: &Settings, name: &str, value: Value) -> Result<Settings, serde_json::Error> {
+    let mut current = serde_json::to_value(settings)?;
+    current[name] = value;
+    let mut patched: Settings = serde_json::from_value(current)?;
+    // Picking a preset sets every field it covers, as in the settings menu
+    if name == "visual_effects" {
+        patched.apply_preset(patched.visual_effects);
+    }
+    Ok(patched)
+}
+
+fn queue(command: BridgeCommand) {
+    COMMANDS.with_borrow_mut(|commands| commands.push(command));
+}
+
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    serde_json::to_string(value)
+        .ok()
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+        .unwrap_or(JsValue::NULL)
+}
+
+fn ball_info(chain: &BallChain, variant: crate::BallVariant) -> BallInfo {
+    let tier = chain.tier(variant);
+    BallInfo {
+        index: variant.0,
+        name: tier.name.clone(),
+        sprite: tier.sprite.clone(),
+    }
+}
+
+fn apply_bridge_commands(
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut settings: ResMut<Settings>,
+    mut restart_events: EventWriter<RestartGame>,
+) {
+    for command in COMMANDS.with_borrow_mut(std::mem::take) {
+        match command {
+            BridgeCommand::Pause => {
+                if *state.get() == GameState::Playing {
+                    next_state.set(GameState::Paused);
+                }
+            }
+            BridgeCommand::Resume => {
+                if *state.get() == GameState::Paused {
+                    next_state.set(GameState::Playing);
+                }
+            }
+            BridgeCommand::Restart => {
+                restart_events.send(RestartGame);
+            }
+            BridgeCommand::SetSetting(name, value) => {
+                match patch_settings(&settings, &name, value) {
+                    Ok(updated) => *settings = updated,
+                    Err(err) => warn!("Ignoring setting {name} from the page: {err}"),
+                }
+            }
+        }
+    }
+}
+
+fn publish_snapshot(
+    score: Res<Score>,
+    state: Res<State<GameState>>,
+    chain: Res<BallChain>,
+    queue: Res<DropQueue>,
+    settings: Res<Settings>,
+    daily_record: Res<DailyRecord>,
+    preview: Query<&BallPreview>,
+) {
+    SNAPSHOT.with_borrow_mut(|snapshot| {
+        snapshot.score = score.current;
+        snapshot.high_score = score.high_score;
+        if state.is_changed() || snapshot.state.is_empty() {
+            snapshot.state = format!("{:?}", state.get());
+        }
+        snapshot.next_ball = preview
+            .get_single()
+            .ok()
+            .and_then(|preview| preview.next_size)
+            .map(|variant| ball_info(&chain, variant));
+        snapshot.upcoming = queue
+            .upcoming(settings.next_queue_length)
+            .map(|variant| ball_info(&chain, variant))
+            .collect();
+        if daily_record.is_changed() || chain.is_changed() {
+            snapshot.daily_share_text = daily_record.share_text(&chain);
+        }
+    });
+}
+
+fn forward_events(
+    chain: Res<BallChain>,
+    mode: Res<GameMode>,
+    mut merged_events: EventReader<BallsMerged>,
+    mut game_ended_events: EventReader<GameEnded>,
+) {
+    for merged in merged_events.read() {
+        let info = to_js(&MergeInfo {
+            from: ball_info(&chain, merged.from),
+            into: ball_info(&chain, merged.into),
+            points: merged.points,
+            combo: merged.combo,
+            x: merged.position.x,
+            y: merged.position.y,
+        });
+        call_all(&MERGE_CALLBACKS, &info);
+    }
+
+    for ended in game_ended_events.read() {
+        let info = to_js(&GameOverInfo {
+            won: ended.outcome == GameOutcome::Won,
+            mode: mode.label(),
+            score: ended.score,
+        });
+        call_all(&GAME_OVER_CALLBACKS, &info);
+    }
+}
+
+fn call_all(callbacks: &'static std::thread::LocalKey<RefCell<Vec<js_sys::Function>>>, arg: &JsValue) {
+    // Clone first so a callback can subscribe another one without a double borrow
+    for callback in callbacks.with_borrow(|callbacks| callbacks.clone()) {
+        if let Err(err) = callback.call1(&JsValue::NULL, arg) {
+            warn!("Page callback failed: {err:?}");
+        }
+    }
+}
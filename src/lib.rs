@@ -1,12 +1,84 @@
-use bevy::{prelude::*, input::touch::Touches};
+use bevy::{prelude::*, input::InputPlugin, state::app::StatesPlugin, time::TimeUpdateStrategy};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::time::Duration;
+
+mod ron_asset;
+mod ball_chain;
+pub use ball_chain::{BallChain, BallTier, BallVariant};
+use ball_chain::{BallChainPlugin, BASE_BALL_SIZE};
+mod ball_shape;
+pub use ball_shape::BallShape;
+use ball_shape::BallShapePlugin;
+mod storage;
+pub use storage::{Storage, StorageBackend, MemoryStorage};
+#[cfg(not(target_arch = "wasm32"))]
+pub use storage::FileStorage;
+#[cfg(target_arch = "wasm32")]
+pub use storage::LocalStorage;
+mod stats;
+pub use stats::Stats;
+use stats::StatsPlugin;
+mod saved_settings;
+use saved_settings::SavedSettingsPlugin;
+mod combo;
+pub use combo::ComboTracker;
+use combo::{ComboLink, ComboPlugin, spawn_combo_label};
+mod merge;
+pub use merge::MergeEvent;
+use merge::{MergePlugin, find_merges};
+#[cfg(target_arch = "wasm32")]
+mod js_bridge;
+#[cfg(target_arch = "wasm32")]
+use js_bridge::JsBridgePlugin;
+mod main_menu;
+pub use main_menu::BallDropMenuPlugin;
+mod pause;
+pub use pause::BallDropPausePlugin;
+use pause::{FreezePlugin, is_frozen};
+mod aim;
+use aim::AimPlugin;
+mod widgets;
+use widgets::WidgetsPlugin;
+mod settings_menu;
+use settings_menu::SettingsMenuPlugin;
+mod drop_queue;
+pub use drop_queue::DropQueue;
+use drop_queue::QueuePanelPlugin;
+mod game_mode;
+pub use game_mode::GameMode;
+use game_mode::GameModePlugin;
+mod blitz;
+pub use blitz::BlitzClock;
+use blitz::{BlitzHudPlugin, BlitzPlugin, is_blitz};
+mod zen;
+pub use zen::BallCleared;
+use zen::{ClearTopLayer, ZenPlugin};
+mod daily;
+pub use daily::{DailyRecord, DailyRun};
+use daily::{DailyPlugin, DailyResultsPlugin, daily_seed, utc_day};
+mod puzzle;
+pub use puzzle::{PlacedBall, PuzzleGoal, PuzzleLevel, PuzzleLevels, PuzzleRun};
+use puzzle::{PuzzleHudPlugin, PuzzlePlugin};
+mod survival;
+pub use survival::{SurvivalClock, SurvivalCurve};
+use survival::{SurvivalHudPlugin, SurvivalPlugin};
+mod hold;
+pub use hold::{HeldBall, HoldBall};
+use hold::{HoldBoxPlugin, HoldPlugin};
+mod input_map;
+pub use input_map::{Action, Binding, InputMap};
+use input_map::{Actions, InputMapPlugin};
 
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
-enum GameState {
+pub enum GameState {
     #[default]
     Playing,
     GameOver,
     Win,
     Settings,
+    MainMenu,
+    Paused,
 }
 
 #[derive(Resource)]
@@ -24,29 +96,83 @@ impl Default for ParticleCount {
     }
 }
 
-#[derive(Resource)]
-struct Score {
-    current: u32,
-    high_score: u32,
+/// The camera the game is drawn, aimed and shaken with. `BallDropPlugin`
+/// spawns one unless an entity already has this marker, so a host app can tag
+/// its own camera instead.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct GameCamera;
+
+#[derive(Resource, Default)]
+pub struct Score {
+    pub current: u32,
+    pub high_score: u32,
 }
 
-impl Default for Score {
-    fn default() -> Self {
-        Self {
-            current: 0,
-            high_score: 0,
-        }
-    }
+/// Drops the current preview ball at world x position `x`, clamped to the
+/// container walls. Pointer input sends these; simulations can send them directly.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DropBall {
+    pub x: f32,
+}
+
+/// Clears the container and starts a fresh game.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct RestartGame;
+
+/// A restart has emptied the container and refilled the queue. Modes that
+/// start from a set board lay it out on this.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct GameStarted;
+
+/// A ball left the dropper and is now in play.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BallDropped {
+    pub entity: Entity,
+    pub variant: BallVariant,
+    pub position: Vec3,
 }
 
-#[derive(Resource, PartialEq, Clone, Copy)]
+/// Two `from` balls merged into a new `into` ball. Sent after the score,
+/// stats and combo have been updated.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BallsMerged {
+    pub entity: Entity,
+    pub from: BallVariant,
+    pub into: BallVariant,
+    pub position: Vec3,
+    /// Points awarded, combo multiplier included
+    pub points: u32,
+    pub combo: u32,
+}
+
+/// A ball crossed into the danger zone; the game ends unless it clears in time.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct DangerStarted;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameOutcome {
+    Lost,
+    Won,
+    /// A timed mode ran out of time
+    TimeUp,
+}
+
+/// The game finished, with the final score.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct GameEnded {
+    pub outcome: GameOutcome,
+    pub score: u32,
+}
+
+#[derive(Resource, PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 enum VisualEffectsLevel {
     Low,
     Normal,
     High,
 }
 
-#[derive(Resource)]
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
 struct Settings {
     volume: f32,
     sound_enabled: bool,
@@ -71,6 +197,16 @@ struct Settings {
     // Physics settings
     ball_bounciness: f32,
     ball_friction: f32,
+    // Seconds between drops from player input, per GameMode; 0 turns the
+    // limit off
+    classic_drop_cooldown: f32,
+    blitz_drop_cooldown: f32,
+    zen_drop_cooldown: f32,
+    daily_drop_cooldown: f32,
+    puzzle_drop_cooldown: f32,
+    survival_drop_cooldown: f32,
+    // How many of the balls after the preview the HUD shows
+    next_queue_length: usize,
 }
 
 impl Default for Settings {
@@ -98,10 +234,126 @@ impl Default for Settings {
             visual_effects: VisualEffectsLevel::Normal,
             ball_bounciness: 0.5,
             ball_friction: 0.5,
+            classic_drop_cooldown: 0.4,
+            blitz_drop_cooldown: 0.25,
+            zen_drop_cooldown: 0.4,
+            daily_drop_cooldown: 0.4,
+            puzzle_drop_cooldown: 0.4,
+            survival_drop_cooldown: 0.4,
+            next_queue_length: 3,
         }
     }
 }
 
+impl Settings {
+    fn drop_cooldown_for(&self, mode: GameMode) -> f32 {
+        match mode {
+            GameMode::Classic => self.classic_drop_cooldown,
+            GameMode::Blitz => self.blitz_drop_cooldown,
+            GameMode::Zen => self.zen_drop_cooldown,
+            GameMode::Daily => self.daily_drop_cooldown,
+            GameMode::Puzzle => self.puzzle_drop_cooldown,
+            GameMode::Survival => self.survival_drop_cooldown,
+        }
+    }
+
+    /// The settings effects should use in `mode`. Zen softens the player's
+    /// own: quieter, fewer and slower particles, and no flashing. Nothing is
+    /// written back, so the saved settings stay as the player left them.
+    fn for_mode(&self, mode: GameMode) -> Cow<'_, Settings> {
+        if mode != GameMode::Zen {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(Settings {
+            volume: self.volume * 0.5,
+            glow_intensity: self.glow_intensity.min(0.01),
+            pulse_magnitude: self.pulse_magnitude.min(0.005),
+            color_speed: self.color_speed.min(0.05),
+            background_animation_speed: self.background_animation_speed.min(0.1),
+            explosion_intensity: self.explosion_intensity.min(0.2),
+            explosion_particle_speed: self.explosion_particle_speed.min(150.0),
+            explosion_particle_count: self.explosion_particle_count.min(6.0),
+            ..self.clone()
+        })
+    }
+
+    fn apply_preset(&mut self, level: VisualEffectsLevel) {
+        match level {
+            VisualEffectsLevel::Low => {
+                self.glow_intensity = 0.01;   // Extremely subtle glow
+                self.pulse_magnitude = 0.005; // Minimal pulse
+                self.color_speed = 0.05;      // Very slow, almost static
+                self.background_animation_speed = 0.1; // Slow background
+                self.background_strip_count = 5;      // Fewer strips
+                self.background_saturation = 0.5;     // Muted colors
+                self.background_brightness = 0.3;     // Darker background
+                // Minimal explosion effects
+                self.explosion_intensity = 0.2;       // Small explosions
+                self.explosion_particle_speed = 150.0; // Slower particles
+                self.explosion_particle_size = 2.0;    // Smaller particles
+                self.explosion_particle_count = 8.0;   // Fewer particles
+                self.explosion_particle_lifetime = 0.2; // Short lifetime
+                self.screen_shake_intensity = 0.2;    // Minimal shake
+                self.screen_shake_decay = 4.0;        // Fast decay
+                self.ball_bounciness = 0.2; // Less bouncy
+                self.ball_friction = 0.7;   // More friction
+            }
+            VisualEffectsLevel::Normal => {
+                self.glow_intensity = 0.05;   // Default moderate glow
+                self.pulse_magnitude = 0.02;  // Default subtle pulse
+                self.color_speed = 0.15;      // Default moderate speed
+                self.background_animation_speed = 0.5; // Normal background
+                self.background_strip_count = 10;     // Normal strips
+                self.background_saturation = 1.0;     // Normal saturation
+                self.background_brightness = 0.5;     // Normal brightness
+                // Default explosion effects
+                self.explosion_intensity = 0.5;       // Medium explosions
+                self.explosion_particle_speed = 350.0; // Normal speed
+                self.explosion_particle_size = 4.0;    // Normal size
+                self.explosion_particle_count = 15.0;  // Normal count
+                self.explosion_particle_lifetime = 0.4; // Normal lifetime
+                self.screen_shake_intensity = 0.5;    // Medium shake
+                self.screen_shake_decay = 3.0;        // Normal decay
+                self.ball_bounciness = 0.3; // Medium bounce
+                self.ball_friction = 0.5;   // Medium friction
+            }
+            VisualEffectsLevel::High => {
+                self.glow_intensity = 0.02;    // Keep glow subtle
+                self.pulse_magnitude = 0.008;  // Very subtle size changes
+                self.color_speed = 2.0;        // Super fast color changes
+                self.background_animation_speed = 0.1; //slow -- my baby is sensitive
+                self.background_strip_count = 40;     // Many strips
+                self.background_saturation = 1.2;     // Very saturated
+                self.background_brightness = 0.8;     // Brighter
+                // Intense explosion effects
+                self.explosion_intensity = 2.0;       // MASSIVE explosions
+                self.explosion_particle_speed = 400.0; // Fast particles
+                self.explosion_particle_size = 8.0;    // Large particles
+                self.explosion_particle_count = 25.0;  // Many particles
+                self.explosion_particle_lifetime = 0.8; // Long lifetime
+                self.screen_shake_intensity = 5.0;    // EXTREME shake
+                self.screen_shake_decay = 1.0;        // Very slow decay
+                self.ball_bounciness = 0.4; // Very bouncy
+                self.ball_friction = 0.2;   // Low friction
+            }
+        }
+        self.visual_effects = level;
+    }
+
+    /// Puts every tuning field back to the current preset, keeping the
+    /// player's audio and fullscreen choices.
+    fn reset_to_preset(&mut self) {
+        let level = self.visual_effects;
+        *self = Settings {
+            volume: self.volume,
+            sound_enabled: self.sound_enabled,
+            is_fullscreen: self.is_fullscreen,
+            ..default()
+        };
+        self.apply_preset(level);
+    }
+}
+
 #[derive(Resource)]
 struct DangerZone {
     warning_timer: Timer,
@@ -126,23 +378,98 @@ impl Default for DangerZone {
 #[derive(Component)]
 struct DangerZoneWarning;
 
+// The container floor; its top edge sits 10px above GROUND_Y
 #[derive(Component)]
-struct ScoreText;
+struct Ground;
+
+const GROUND_Y: f32 = -300.0;
 
 #[derive(Component)]
+struct ScoreText;
+
+#[derive(Component, Default)]
 struct GameOverText;
 use bevy_rapier2d::{plugin::RapierPhysicsPlugin, prelude::*};
 use std::f32::consts::PI;
 
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Every random draw in the game goes through here. `queue` decides which balls
+/// drop, `gameplay` the spawn jitter and `cosmetic` particles and ball phases.
+/// Keeping them apart means the same seed deals the same balls however the
+/// game plays out, and effects settings never shift the gameplay stream.
+///
+/// Insert `GameRng::new(seed)` before adding `BallDropPlugin` to replay the same
+/// game on every restart; the default picks a fresh seed for each game.
+#[derive(Resource)]
+pub struct GameRng {
+    // The current game's seed
+    seed: u64,
+    // What every restart goes back to, if set with `new`
+    fixed_seed: Option<u64>,
+    pub queue: ChaCha8Rng,
+    pub gameplay: ChaCha8Rng,
+    pub cosmetic: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            fixed_seed: Some(seed),
+            ..Self::with_seed(seed)
+        }
+    }
+
+    fn with_seed(seed: u64) -> Self {
+        let gameplay = ChaCha8Rng::seed_from_u64(seed);
+        let mut cosmetic = ChaCha8Rng::seed_from_u64(seed);
+        cosmetic.set_stream(1);
+        let mut queue = ChaCha8Rng::seed_from_u64(seed);
+        queue.set_stream(2);
+        Self {
+            seed,
+            fixed_seed: None,
+            queue,
+            gameplay,
+            cosmetic,
+        }
+    }
+
+    /// The seed the current game's streams were started from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Rewinds the streams for a new game, picking a new seed unless one was fixed.
+    pub fn restart(&mut self) {
+        let seed = self.fixed_seed.unwrap_or_else(rand::random);
+        self.restart_with(seed);
+    }
+
+    /// Rewinds the streams for a new game played with `seed`. Later restarts
+    /// go back to the usual seed choice, so a fixed seed survives a one-off
+    /// game with another.
+    pub fn restart_with(&mut self, seed: u64) {
+        *self = Self {
+            fixed_seed: self.fixed_seed,
+            ..Self::with_seed(seed)
+        };
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::with_seed(rand::random())
+    }
+}
 
 /// A utility struct for generating random colors
 pub struct ColorGenerator;
 
 impl ColorGenerator {
     /// Generate a random SRGBA color
-    pub fn random_srgba() -> Srgba {
-        let mut rng = rand::thread_rng();
+    pub fn random_srgba(rng: &mut impl Rng) -> Srgba {
         Srgba::new(
             rng.gen_range(0.0..=1.0),
             rng.gen_range(0.0..=1.0),
@@ -152,8 +479,7 @@ impl ColorGenerator {
     }
 
     /// Generate a random HSLA color
-    pub fn random_hsla() -> Hsla {
-        let mut rng = rand::thread_rng();
+    pub fn random_hsla(rng: &mut impl Rng) -> Hsla {
         Hsla::new(
             rng.gen_range(0.0..360.0), // Hue: 0-360 degrees
             rng.gen_range(0.0..=1.0),   // Saturation: 0-1
@@ -163,15 +489,14 @@ impl ColorGenerator {
     }
 
     /// Generate a random LinearRGBA color
-    pub fn random_linear_rgba() -> LinearRgba {
+    pub fn random_linear_rgba(rng: &mut impl Rng) -> LinearRgba {
         // Generate SRGBA first and convert to linear
-        let srgb = Self::random_srgba();
+        let srgb = Self::random_srgba(rng);
         LinearRgba::from(srgb)
     }
 
     /// Generate a random vibrant color in SRGBA
-    pub fn random_vibrant_srgba() -> Srgba {
-        let mut rng = rand::thread_rng();
+    pub fn random_vibrant_srgba(rng: &mut impl Rng) -> Srgba {
         let hsla = Hsla::new(
             rng.gen_range(0.0..360.0), // Random hue
             rng.gen_range(0.8..=1.0),   // High saturation
@@ -182,8 +507,7 @@ impl ColorGenerator {
     }
 
     /// Generate a random pastel color in SRGBA
-    pub fn random_pastel_srgba() -> Srgba {
-        let mut rng = rand::thread_rng();
+    pub fn random_pastel_srgba(rng: &mut impl Rng) -> Srgba {
         let hsla = Hsla::new(
             rng.gen_range(0.0..360.0), // Random hue
             rng.gen_range(0.3..=0.5),   // Lower saturation
@@ -209,10 +533,8 @@ fn spawn_explosion(
     color: Color,
     settings: &Settings,
     particle_count: &mut ResMut<ParticleCount>,
+    rng: &mut impl Rng,
 ) {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    
     // Scale particle count with explosion intensity, but cap it for performance
     let base_particles = (settings.explosion_particle_count * (1.0 + settings.explosion_intensity)).min(30.0) as i32;
     let available_slots = particle_count.max.saturating_sub(particle_count.current);
@@ -264,7 +586,7 @@ fn spawn_explosion(
             },
             ExplosionParticle {
                 lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
-                velocity: velocity,
+                velocity,
                 rotation_speed: rng.gen_range(-3.0..3.0),
                 initial_color: varied_color,
             },
@@ -285,10 +607,12 @@ fn spawn_explosion(
 fn update_screen_shake(
     time: Res<Time>,
     mut shake_state: ResMut<ScreenShakeState>,
-    mut camera_query: Query<&mut Transform, With<Camera>>,
+    mut camera_query: Query<&mut Transform, With<GameCamera>>,
     settings: Res<Settings>,
 ) {
-    let mut camera_transform = camera_query.single_mut();
+    let Ok(mut camera_transform) = camera_query.get_single_mut() else {
+        return;
+    };
     
     // Clamp trauma between 0 and 1
     shake_state.trauma = shake_state.trauma.clamp(0.0, 1.0);
@@ -359,139 +683,6 @@ fn update_explosion_particles(
 }
 
 
-// Base size for scaling all balls
-const BASE_BALL_SIZE: f32 = 45.0;
-
-#[derive(Copy, Clone, PartialEq)]
-enum BallVariant {
-    // Tier 1 (Starting balls)
-    Sad,        // Order: 1
-    Angry,      // Order: 2  
-    Surprised,  // Order: 3
-
-    // Tier 2 (First combinations)
-    Embarrassed, // Order: 4
-    Happy,      // Order: 5
-    Joyful,     // Order: 6
-
-    // Tier 3 (Advanced combinations)
-    Spite,      // Order: 7
-    Love,       // Order: 8
-    Pride,      // Order: 9
-
-    // Tier 4 (Final form)
-    Rage,       // Order: 10
-
-    // Victory state
-    Win,        // Order: 11
-}
-
-impl BallVariant {
-    // Returns the canonical order of this variant (1-based)
-    fn order(&self) -> u32 {
-        match self {
-            // Tier 1
-            BallVariant::Sad => 1,
-            BallVariant::Angry => 2,
-            BallVariant::Surprised => 3,
-            
-            // Tier 2
-            BallVariant::Embarrassed => 4,
-            BallVariant::Happy => 5,
-            BallVariant::Joyful => 6,
-            
-            // Tier 3
-            BallVariant::Spite => 7,
-            BallVariant::Love => 8,
-            BallVariant::Pride => 9,
-            
-            // Tier 4
-            BallVariant::Rage => 10,
-            
-            // Victory
-            BallVariant::Win => 11,
-        }
-    }
-}
-
-impl BallVariant {
-    fn size(&self) -> f32 {
-        // Start at 0.9 and grow by 25% each step
-        let ratio = 0.6 * (1.27f32.powf((self.order() - 1) as f32));
-        BASE_BALL_SIZE * ratio
-    }
-
-    fn sprite_path(&self) -> &'static str {
-        match self {
-            BallVariant::Sad => "sad_sprite.png",
-            BallVariant::Angry => "angry_sprite.png",
-            BallVariant::Surprised => "surprise_sprite.png",
-            BallVariant::Embarrassed => "embarassed_sprite.png",
-            BallVariant::Happy => "happy_sprite.png",
-            BallVariant::Joyful => "joyful_sprite.png",
-            BallVariant::Spite => "spite_sprite.png",
-            BallVariant::Love => "love_sprite.png",
-            BallVariant::Pride => "pride_sprite.png",
-            BallVariant::Rage => "rage_sprite.png",
-            BallVariant::Win => "win_sprite.png",
-        }
-    }
-
-    fn next_variant(&self) -> Option<Self> {
-        match self.order() {
-            11 => None,  // Win is final form
-            n => match n + 1 {
-                2 => Some(BallVariant::Angry),
-                3 => Some(BallVariant::Surprised),
-                4 => Some(BallVariant::Embarrassed),
-                5 => Some(BallVariant::Happy),
-                6 => Some(BallVariant::Joyful),
-                7 => Some(BallVariant::Spite),
-                8 => Some(BallVariant::Love),
-                9 => Some(BallVariant::Pride),
-                10 => Some(BallVariant::Rage),
-                11 => Some(BallVariant::Win),
-                _ => None,  // Shouldn't happen
-            }
-        }
-    }
-
-    fn random() -> Self {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
-        
-        // List all variants with exponentially decreasing weights based on size
-        let variants = [
-            (BallVariant::Sad, 100.0),  // Most common
-            (BallVariant::Angry, 80.0),
-            (BallVariant::Surprised, 60.0),
-            (BallVariant::Embarrassed, 25.0),
-            (BallVariant::Happy, 10.0),
-            (BallVariant::Joyful, 4.0),
-            (BallVariant::Spite, 1.0),
-            (BallVariant::Love, 0.4),
-            (BallVariant::Pride, 0.1),
-            (BallVariant::Rage, 0.01),  // Extremely rare
-        ];
-        
-        // Calculate total weight
-        let total_weight: f32 = variants.iter().map(|(_, weight)| weight).sum();
-        
-        // Generate random value
-        let mut value = rng.gen::<f32>() * total_weight;
-        
-        // Select variant based on weights
-        for (variant, weight) in variants.iter() {
-            value -= weight;
-            if value <= 0.0 {
-                return *variant;
-            }
-        }
-        
-        // Fallback to smallest ball if something goes wrong
-        BallVariant::Sad
-    }
-}
 
 
 #[derive(Component)]
@@ -509,19 +700,10 @@ struct ExplosionParticle {
 }
 
 
-#[derive(Resource)]
+// Decays at the screen_shake_decay setting's rate
+#[derive(Resource, Default)]
 struct ScreenShakeState {
     trauma: f32,
-    decay: f32,
-}
-
-impl Default for ScreenShakeState {
-    fn default() -> Self {
-        Self {
-            trauma: 0.0,
-            decay: 3.0, // Faster decay for snappier feel
-        }
-    }
 }
 
 
@@ -529,26 +711,29 @@ impl Default for ScreenShakeState {
 struct BackgroundStrip {
     hue: f32,         // Current hue
     speed: f32,       // How fast this strip changes
-    width: f32,       // Width of the strip
 }
 
 fn setup_background(mut commands: Commands, settings: Res<Settings>) {
+    spawn_background_strips(&mut commands, &settings);
+}
+
+fn spawn_background_strips(commands: &mut Commands, settings: &Settings) {
     // Create several vertical strips based on settings
-    let num_strips = settings.background_strip_count;
+    let num_strips = settings.background_strip_count.max(1);
     let strip_width = 500.0 / num_strips as f32;
     
     for i in 0..num_strips {
         let x_pos = -250.0 + (i as f32 * strip_width) + (strip_width / 2.0);
+        let hue = (i as f32 / num_strips as f32) * 360.0; // Starting hue in degrees
         
         commands.spawn((
             BackgroundStrip {
-                hue: (i as f32 / num_strips as f32) * 360.0, // Starting hue in degrees
+                hue,
                 speed: 0.05, // Base speed that will be multiplied by effects.color_speed
-                width: strip_width,
             },
             SpriteBundle {
                 sprite: Sprite {
-                    color: Color::hsl(0.0, 1.0, 0.5),
+                    color: Color::hsl(hue, settings.background_saturation, settings.background_brightness),
                     custom_size: Some(Vec2::new(strip_width, 600.0)),
                     ..default()
                 },
@@ -558,11 +743,29 @@ fn setup_background(mut commands: Commands, settings: Res<Settings>) {
         ));
     }
 }
+
+// Respawns the strips when the strip count setting no longer matches them
+fn rebuild_background(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    strips: Query<Entity, With<BackgroundStrip>>,
+) {
+    if strips.iter().count() == settings.background_strip_count.max(1) as usize {
+        return;
+    }
+    for entity in &strips {
+        commands.entity(entity).despawn();
+    }
+    spawn_background_strips(&mut commands, &settings);
+}
+
 fn animate_background(
     time: Res<Time>,
     mut strips: Query<(&mut Sprite, &mut BackgroundStrip)>,
     settings: Res<Settings>,
+    mode: Res<GameMode>,
 ) {
+    let settings = settings.for_mode(*mode);
     for (mut sprite, mut strip) in &mut strips {
         // Update the hue based on visual effects settings
         strip.hue += strip.speed * settings.background_animation_speed * 360.0 * time.delta_seconds();
@@ -588,34 +791,48 @@ struct GameSounds {
 #[derive(Component)]
 struct GameAudio;
 
+/// Where closing the settings overlay goes back to.
+#[derive(Resource)]
+struct SettingsReturnState(GameState);
+
+impl Default for SettingsReturnState {
+    fn default() -> Self {
+        Self(GameState::Playing)
+    }
+}
+
 fn toggle_settings_menu(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mut next_state: ResMut<NextState<GameState>>,
     current_state: Res<State<GameState>>,
+    mut settings_return: ResMut<SettingsReturnState>,
 ) {
     let settings_clicked = SETTINGS_BUTTON_CLICKED.swap(false, std::sync::atomic::Ordering::SeqCst);
     
-    if keyboard.just_pressed(KeyCode::Escape) || settings_clicked {
-        match current_state.get() {
-            GameState::Playing => next_state.set(GameState::Settings),
-            GameState::Settings => next_state.set(GameState::Playing),
-            _ => {},
+    let settings_pressed = settings_clicked || actions.just_pressed(Action::Settings);
+    let pause_pressed = actions.just_pressed(Action::Pause);
+
+    // Pause toggles the pause screen; Settings (or the page's button) opens settings directly
+    match current_state.get() {
+        GameState::Playing if pause_pressed => next_state.set(GameState::Paused),
+        GameState::Paused if pause_pressed => next_state.set(GameState::Playing),
+        state @ (GameState::Playing | GameState::Paused | GameState::MainMenu) if settings_pressed => {
+            settings_return.0 = *state;
+            next_state.set(GameState::Settings);
         }
+        GameState::Settings if pause_pressed || settings_pressed => {
+            next_state.set(settings_return.0);
+        }
+        _ => {},
     }
 }
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
-
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 pub fn settings_clicked() {
-    use bevy::ecs::system::Resource;
-    use bevy::prelude::*;
-    
     // Set the clicked flag
     SETTINGS_BUTTON_CLICKED.store(true, std::sync::atomic::Ordering::SeqCst);
 }
@@ -628,428 +845,218 @@ pub fn wasm_main() {
     main();
 }
 
-pub fn main() {
-    
-    App::new()
-        .insert_resource(Settings::default())
-        .insert_resource(Score::default())
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                resolution: (500.0, 600.0).into(),
-                title: "Ball Drop".to_string(),
-                resizable: false,
-                ..default()
-            }),
-            ..default()
-        }))
-        .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
-        .insert_resource(ParticleCount::default())
-        .insert_resource(ScreenShakeState::default())
-        .insert_resource(RapierConfiguration {
-            gravity: Vec2::new(0.0, -1200.0),
-            ..RapierConfiguration::new(1.0)
-        })
-        .insert_state::<GameState>(GameState::Playing)
-        .insert_resource(DangerZone::default())
-        .add_systems(Startup, (
-            setup,
-            setup_preview,
-            setup_background,
-            setup_audio,
-            setup_danger_zone,
-        ))
-        .add_systems(Update, (
-            IntoSystem::into_system(spawn_ball),
-            IntoSystem::into_system(handle_ball_collisions).after(spawn_ball)
-        ).run_if(not(in_state(GameState::Settings))))
-        .add_systems(Update, (
-            update_preview,
-            animate_background,
-            handle_collision_effects,
-            update_explosion_particles,
-            update_screen_shake,
-            check_danger_zone,
-        ).run_if(in_state(GameState::Playing)))
-        .add_systems(Update, update_ball_effects.run_if(in_state(GameState::Playing)))
-        .add_systems(OnEnter(GameState::GameOver), setup_game_over)
-        .add_systems(OnEnter(GameState::Win), setup_win_screen)
-        .add_systems(Update, handle_game_over.run_if(in_state(GameState::GameOver)))
-        .add_systems(Update, handle_win_screen.run_if(in_state(GameState::Win)))
-        .add_systems(Update, (toggle_settings_menu, handle_global_restart).chain())
-        .add_systems(OnEnter(GameState::Settings), setup_settings_menu)
-        .add_systems(OnExit(GameState::Settings), cleanup_settings_menu)
-        .add_systems(Update, (
-            settings_menu_interaction,
-            update_button_colors,
-            update_audio_volume,
-        ).run_if(in_state(GameState::Settings)))
-        .run();
+/// Core gameplay: the container, dropping, merging, danger zone and the
+/// game over / win flow. Adds Rapier itself unless the host app already has it.
+pub struct BallDropPlugin;
 
-fn update_audio_volume(
-    settings: Res<Settings>,
-    audio_query: Query<&AudioSink, With<GameAudio>>,
-) {
-    for sink in audio_query.iter() {
-        if settings.sound_enabled {
-            sink.set_volume(settings.volume);
-        } else {
-            sink.set_volume(0.0);
+impl Plugin for BallDropPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<RapierPhysicsPlugin<NoUserData>>() {
+            app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
         }
+
+        app.add_plugins((BallChainPlugin, BallShapePlugin, StatsPlugin, SavedSettingsPlugin, ComboPlugin, MergePlugin, FreezePlugin, InputMapPlugin, HoldPlugin, GameModePlugin, BlitzPlugin, ZenPlugin, DailyPlugin, PuzzlePlugin, SurvivalPlugin));
+
+        app.init_resource::<Settings>()
+            .init_resource::<Storage>()
+            .init_resource::<Score>()
+            .insert_resource(RapierConfiguration {
+                gravity: Vec2::new(0.0, -1200.0),
+                ..RapierConfiguration::new(1.0)
+            })
+            .insert_state::<GameState>(GameState::Playing)
+            .insert_resource(DangerZone::default())
+            .init_resource::<GameRng>()
+            .init_resource::<DropQueue>()
+            .add_event::<DropBall>()
+            .add_event::<RestartGame>()
+            .add_event::<GameStarted>()
+            .add_event::<BallDropped>()
+            .add_event::<BallsMerged>()
+            .add_event::<DangerStarted>()
+            .add_event::<GameEnded>()
+            .add_systems(Startup, (
+                setup,
+                setup_preview,
+                setup_danger_zone,
+            ))
+            .add_systems(PostStartup, spawn_game_camera)
+            .add_systems(Update, (
+                drop_ball,
+                find_merges.after(drop_ball),
+                apply_merges.after(find_merges),
+            ).run_if(not(is_frozen)))
+            .add_systems(Update, update_preview_sprite.after(drop_ball).after(restart_game))
+            .add_systems(Update, apply_ball_physics_settings.run_if(resource_changed::<Settings>))
+            .add_systems(Update, check_danger_zone.run_if(in_state(GameState::Playing)))
+            .add_systems(Update, handle_game_over.run_if(in_state(GameState::GameOver)))
+            .add_systems(Update, handle_win_screen.run_if(in_state(GameState::Win)))
+            .add_systems(Update, handle_global_restart)
+            .add_systems(Update, restart_game.after(handle_game_over).after(handle_win_screen).after(handle_global_restart));
     }
 }
 
-#[derive(Component)]
-struct SettingsMenu;
+/// Aiming and dropping with the mouse, touch, the move and drop actions of the
+/// `InputMap` (arrow keys / A-D and Space by default), or a gamepad's left
+/// stick. Needs a window and the `GameCamera`.
+pub struct BallDropInputPlugin;
 
-#[derive(Component, PartialEq, Clone, Copy)]
-enum SettingButton {
-    SoundToggle,
-    LowEffects,
-    NormalEffects,
-    HighEffects,
+impl Plugin for BallDropInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(AimPlugin);
+    }
 }
 
+/// Loads the game sounds and keeps playing sounds in sync with `Settings`.
+pub struct BallDropAudioPlugin;
 
-#[derive(Resource, PartialEq, Clone, Copy)]
-struct SelectedEffectsSetting(SettingButton);
-
-fn get_current_effects_level(settings: &Settings) -> SettingButton {
-    match settings.visual_effects {
-        VisualEffectsLevel::Low => SettingButton::LowEffects,
-        VisualEffectsLevel::Normal => SettingButton::NormalEffects,
-        VisualEffectsLevel::High => SettingButton::HighEffects,
+impl Plugin for BallDropAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_audio)
+            .add_systems(Update, play_event_sounds)
+            .add_systems(Update, update_audio_volume.run_if(in_state(GameState::Settings)));
     }
 }
 
+/// Purely cosmetic effects: animated background, particles, screen shake and
+/// the glow/pulse on balls.
+pub struct BallDropEffectsPlugin;
+
+impl Plugin for BallDropEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ParticleCount::default())
+            .insert_resource(ScreenShakeState::default())
+            .add_systems(Startup, setup_background)
+            .add_systems(Update, rebuild_background.run_if(resource_changed::<Settings>))
+            .add_systems(Update, (merge_effects, clear_effects))
+            .add_systems(Update, (
+                animate_background,
+                handle_collision_effects,
+                update_explosion_particles,
+                update_screen_shake,
+                update_ball_effects,
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
 
-fn setup_settings_menu(mut commands: Commands, settings: Res<Settings>) {
-    // Initialize the selected effects setting based on current settings
-    commands.insert_resource(SelectedEffectsSetting(get_current_effects_level(&settings)));
-    commands
-        .spawn((
-            NodeBundle {
-                style: Style {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    position_type: PositionType::Absolute,
-                    flex_direction: FlexDirection::Column,
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    row_gap: Val::Px(20.0),
-                    ..default()
-                },
-                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
-                ..default()
-            },
-            SettingsMenu,
-        ))
-        .with_children(|parent| {
-            // Title
-            parent.spawn(TextBundle::from_section(
-                "Settings Menu\nPress ESC to return",
-                TextStyle {
-                    font_size: 40.0,
-                    color: Color::WHITE,
-                    ..default()
-                },
-            ));
-
-            // Sound Toggle Button
-            parent.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(200.0),
-                        height: Val::Px(50.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    background_color: BackgroundColor(if settings.sound_enabled {
-                        Color::srgb(0.2, 0.8, 0.2)
-                    } else {
-                        Color::srgb(0.8, 0.2, 0.2)
-                    }),
-                    ..default()
-                },
-                SettingButton::SoundToggle,
-            ))
-            .with_children(|parent| {
-                parent.spawn(TextBundle::from_section(
-                    if settings.sound_enabled { "Sound: ON" } else { "Sound: OFF" },
-                    TextStyle {
-                        font_size: 20.0,
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                ));
-            });
+/// Score display, the upcoming balls, the hold slot, and the game over and win
+/// overlays.
+pub struct BallDropUiPlugin;
+
+impl Plugin for BallDropUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((QueuePanelPlugin, HoldBoxPlugin, BlitzHudPlugin, DailyResultsPlugin, PuzzleHudPlugin, SurvivalHudPlugin))
+            .add_systems(Startup, setup_score_text)
+            .add_systems(Update, update_score_text)
+            .add_systems(OnEnter(GameState::GameOver), setup_game_over.run_if(not(is_blitz)))
+            .add_systems(OnExit(GameState::GameOver), despawn_all::<GameOverText>)
+            .add_systems(OnEnter(GameState::Win), setup_win_screen)
+            .add_systems(OnExit(GameState::Win), despawn_all::<WinText>);
+    }
+}
 
-            // Visual Effects Header
-            parent.spawn(TextBundle::from_section(
-                "Visual Effects:",
-                TextStyle {
-                    font_size: 25.0,
-                    color: Color::WHITE,
-                    ..default()
-                },
-            ));
+/// The in-game settings overlay, opened with the Settings action or the page's
+/// settings button.
+pub struct BallDropSettingsPlugin;
 
+impl Plugin for BallDropSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((WidgetsPlugin, SettingsMenuPlugin))
+            .init_resource::<SettingsReturnState>()
+            .add_systems(Update, toggle_settings_menu.before(handle_global_restart));
+    }
+}
 
-            // Preset Buttons Header
-            parent.spawn(TextBundle::from_section(
-                "Preset Effects Levels:",
-                TextStyle {
-                    font_size: 25.0,
-                    color: Color::WHITE,
-                    ..default()
-                },
-            ));
-
-            // Preset Effects Button
-            parent.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(150.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    background_color: BackgroundColor(
-                        if get_current_effects_level(&settings) == SettingButton::LowEffects {
-                            Color::srgb(0.2, 0.8, 0.2) // Green for selected
-                        } else {
-                            Color::srgb(0.4, 0.4, 0.4) // Gray for unselected
-                        }
-                    ),
-                    ..default()
-                },
-                SettingButton::LowEffects,
-            ))
-            .with_children(|parent| {
-                parent.spawn(TextBundle::from_section(
-                    "Low",
-                    TextStyle {
-                        font_size: 20.0,
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                ));
-            });
+/// Builds an app that runs Rapier and the gameplay systems with no window,
+/// renderer, audio or sprite loading. Each `update()` advances the game by
+/// exactly one 60 Hz frame, independent of wall-clock time. Drive it by
+/// sending `DropBall` events and read `Score` and `State<GameState>` back.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        HierarchyPlugin,
+        InputPlugin,
+        StatesPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(HEADLESS_TIMESTEP)))
+    .insert_resource(Storage::in_memory())
+    .add_plugins(BallDropPlugin);
+
+    app.world_mut().resource_mut::<RapierConfiguration>().timestep_mode = TimestepMode::Fixed {
+        dt: HEADLESS_TIMESTEP,
+        substeps: 1,
+    };
+    app
+}
 
-            // Normal Effects Button
-            parent.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(150.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    background_color: BackgroundColor(
-                        if get_current_effects_level(&settings) == SettingButton::NormalEffects {
-                            Color::srgb(0.2, 0.8, 0.2) // Green for selected
-                        } else {
-                            Color::srgb(0.4, 0.4, 0.4) // Gray for unselected
-                        }
-                    ),
-                    ..default()
-                },
-                SettingButton::NormalEffects,
-            ))
-            .with_children(|parent| {
-                parent.spawn(TextBundle::from_section(
-                    "Normal",
-                    TextStyle {
-                        font_size: 20.0,
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                ));
-            });
+const HEADLESS_TIMESTEP: f32 = 1.0 / 60.0;
 
-            // High Effects Button
-            parent.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(150.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    background_color: BackgroundColor(
-                        if get_current_effects_level(&settings) == SettingButton::HighEffects {
-                            Color::srgb(0.2, 0.8, 0.2) // Green for selected
-                        } else {
-                            Color::srgb(0.4, 0.4, 0.4) // Gray for unselected
-                        }
-                    ),
-                    ..default()
-                },
-                SettingButton::HighEffects,
-            ))
-            .with_children(|parent| {
-                parent.spawn(TextBundle::from_section(
-                    "High",
-                    TextStyle {
-                        font_size: 20.0,
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                ));
-            });
-        });
-}
+pub fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            resolution: (500.0, 600.0).into(),
+            title: "Ball Drop".to_string(),
+            resizable: false,
+            ..default()
+        }),
+        ..default()
+    }))
+    .add_plugins((
+        BallDropPlugin,
+        BallDropInputPlugin,
+        BallDropAudioPlugin,
+        BallDropEffectsPlugin,
+        BallDropUiPlugin,
+        BallDropSettingsPlugin,
+        BallDropMenuPlugin,
+        BallDropPausePlugin,
+    ));
 
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(JsBridgePlugin);
 
-fn settings_menu_interaction(
-    mut commands: Commands,
-    mut settings: ResMut<Settings>,
-    mut interaction_query: Query<
-        (&Interaction, Option<&SettingButton>, &mut BackgroundColor, &Children),
-        Changed<Interaction>,
-    >,
-    mut text_query: Query<&mut Text>,
-    _windows: Query<&Window>,
-) {
-    for (interaction, button, mut color, children) in &mut interaction_query {
-        if *interaction == Interaction::Pressed {
-            if let Some(button) = button {
-                match button {
-                    SettingButton::SoundToggle => {
-                    settings.sound_enabled = !settings.sound_enabled;
-                    *color = BackgroundColor(if settings.sound_enabled {
-                        Color::srgb(0.2, 0.8, 0.2)
-                    } else {
-                        Color::srgb(0.8, 0.2, 0.2)
-                    });
-                    // Update button text
-                    if let Some(child) = children.first() {
-                        if let Ok(mut text) = text_query.get_mut(*child) {
-                            text.sections[0].value = if settings.sound_enabled {
-                                "Sound: ON".to_string()
-                            } else {
-                                "Sound: OFF".to_string()
-                            };
-                        }
-                    }
-                }
-                    SettingButton::LowEffects => {
-                        settings.glow_intensity = 0.01;   // Extremely subtle glow
-                        settings.pulse_magnitude = 0.005; // Minimal pulse
-                        settings.color_speed = 0.05;      // Very slow, almost static
-                        settings.background_animation_speed = 0.1; // Slow background
-                        settings.background_strip_count = 5;      // Fewer strips
-                        settings.background_saturation = 0.5;     // Muted colors
-                        settings.background_brightness = 0.3;     // Darker background
-                        // Minimal explosion effects
-                        settings.explosion_intensity = 0.2;       // Small explosions
-                        settings.explosion_particle_speed = 150.0; // Slower particles
-                        settings.explosion_particle_size = 2.0;    // Smaller particles
-                        settings.explosion_particle_count = 8.0;   // Fewer particles
-                        settings.explosion_particle_lifetime = 0.2; // Short lifetime
-                        settings.screen_shake_intensity = 0.2;    // Minimal shake
-                        settings.screen_shake_decay = 4.0;        // Fast decay
-                        settings.visual_effects = VisualEffectsLevel::Low;
-                        settings.ball_bounciness = 0.2; // Less bouncy
-                        settings.ball_friction = 0.7;   // More friction
-                        commands.insert_resource(SelectedEffectsSetting(*button));
-                    }
-                    SettingButton::NormalEffects => {
-                        settings.glow_intensity = 0.05;   // Default moderate glow
-                        settings.pulse_magnitude = 0.02;  // Default subtle pulse
-                        settings.color_speed = 0.15;      // Default moderate speed
-                        settings.background_animation_speed = 0.5; // Normal background
-                        settings.background_strip_count = 10;     // Normal strips
-                        settings.background_saturation = 1.0;     // Normal saturation
-                        settings.background_brightness = 0.5;     // Normal brightness
-                        // Default explosion effects
-                        settings.explosion_intensity = 0.5;       // Medium explosions
-                        settings.explosion_particle_speed = 350.0; // Normal speed
-                        settings.explosion_particle_size = 4.0;    // Normal size
-                        settings.explosion_particle_count = 15.0;  // Normal count
-                        settings.explosion_particle_lifetime = 0.4; // Normal lifetime
-                        settings.screen_shake_intensity = 0.5;    // Medium shake
-                        settings.screen_shake_decay = 3.0;        // Normal decay
-                        settings.visual_effects = VisualEffectsLevel::Normal;
-                        settings.ball_bounciness = 0.3; // Medium bounce
-                        settings.ball_friction = 0.5;   // Medium friction
-                        commands.insert_resource(SelectedEffectsSetting(*button));
-                    }
-                    SettingButton::HighEffects => {
-                        settings.glow_intensity = 0.02;    // Keep glow subtle
-                        settings.pulse_magnitude = 0.008;  // Very subtle size changes
-                        settings.color_speed = 2.0;        // Super fast color changes
-                        settings.background_animation_speed = 0.1; //slow -- my baby is sensitive
-                        settings.background_strip_count = 40;     // Many strips
-                        settings.background_saturation = 1.2;     // Very saturated
-                        settings.background_brightness = 0.8;     // Brighter
-                        // Intense explosion effects
-                        settings.explosion_intensity = 2.0;       // MASSIVE explosions
-                        settings.explosion_particle_speed = 400.0; // Fast particles
-                        settings.explosion_particle_size = 8.0;    // Large particles
-                        settings.explosion_particle_count = 25.0;  // Many particles
-                        settings.explosion_particle_lifetime = 0.8; // Long lifetime
-                        settings.screen_shake_intensity = 5.0;    // EXTREME shake
-                        settings.screen_shake_decay = 1.0;        // Very slow decay
-                        settings.visual_effects = VisualEffectsLevel::High;
-                        settings.ball_bounciness = 0.4; // Very bouncy
-                        settings.ball_friction = 0.2;   // Low friction
-                        commands.insert_resource(SelectedEffectsSetting(*button));
-                    }
-                }
-            }
-        }
-    }
+    app.run();
 }
 
-fn update_button_colors(
-    selected: Res<SelectedEffectsSetting>,
-    mut query: Query<(&SettingButton, &mut BackgroundColor)>,
+fn update_audio_volume(
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    audio_query: Query<&AudioSink, With<GameAudio>>,
 ) {
-    for (button, mut color) in &mut query {
-        if matches!(button, SettingButton::LowEffects | SettingButton::NormalEffects | SettingButton::HighEffects) {
-            *color = BackgroundColor(
-                if *button == selected.0 {
-                    Color::srgb(0.2, 0.8, 0.2)
-                } else {
-                    Color::srgb(0.4, 0.4, 0.4)
-                }
-            );
+    let settings = settings.for_mode(*mode);
+    for sink in audio_query.iter() {
+        if settings.sound_enabled {
+            sink.set_volume(settings.volume);
+        } else {
+            sink.set_volume(0.0);
         }
     }
 }
 
-
-fn cleanup_settings_menu(
-    mut commands: Commands,
-    query: Query<Entity, With<SettingsMenu>>,
-) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn_recursive();
-    }
-}
-}
-
-
 // New system to create the preview ball
 #[derive(Component)]
 struct BallPreview {
-    next_size: BallVariant,
+    // None once a puzzle's balls have all been dropped
+    next_size: Option<BallVariant>,
 }
 
-fn setup_preview(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let next_size = BallVariant::random();
-    let ball_size = next_size.size();
-    
+fn setup_preview(
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    mut queue: ResMut<DropQueue>,
+    settings: Res<Settings>,
+    chain: Res<BallChain>,
+) {
+    let next_size = queue.next(&mut rng.queue, &chain, settings.next_queue_length);
+
+    // update_preview_sprite fills in the texture and size
     commands.spawn((
         BallPreview { next_size },
         SpriteBundle {
-            texture: asset_server.load(next_size.sprite_path()),
             sprite: Sprite {
-                custom_size: Some(Vec2::new(ball_size, ball_size)),
                 color: Color::srgba(1.0, 1.0, 1.0, 0.5), // 50% transparent
                 ..default()
             },
@@ -1060,72 +1067,58 @@ fn setup_preview(mut commands: Commands, asset_server: Res<AssetServer>) {
     ));
 }
 
-// New system to update the preview position
-fn update_preview(
-    mut preview_query: Query<(&mut Transform, &mut Visibility, &BallPreview)>,
-    camera_q: Query<(&Camera, &GlobalTransform)>,
-    windows: Query<&Window>,
-    touches: Res<Touches>,
-    mouse: Res<ButtonInput<MouseButton>>,
+// Whatever changed the next ball (a drop, a restart, the hold slot), show it
+fn update_preview_sprite(
+    asset_server: Option<Res<AssetServer>>,
+    chain: Res<BallChain>,
+    mut preview_query: Query<(&BallPreview, &mut Handle<Image>, &mut Sprite), Changed<BallPreview>>,
 ) {
-    let (camera, camera_transform) = camera_q.single();
-    let window = windows.single();
-    
-    // Get position from either touch or mouse
-    let input_position = if touches.iter().next().is_some() {
-        touches.iter().next().map(|touch| touch.position())
-    } else {
-        window.cursor_position()
-    };
-    
-    if let Some(position) = input_position {
-        if let Some(world_position) = camera.viewport_to_world(camera_transform, position)
-            .map(|ray| ray.origin.truncate())
-    {
-        if let Ok((mut transform, mut visibility, preview)) = preview_query.get_single_mut() {
-            transform.translation.x = world_position.x;
-            // Position higher based on ball size to prevent clipping
-            transform.translation.y = 300.0 - (preview.next_size.size() / 2.0) - 30.0;
-            *visibility = Visibility::Visible;
-        }
-    } else {
-        if let Ok((_, mut visibility, _)) = preview_query.get_single_mut() {
-            *visibility = Visibility::Hidden;
-        }
+    for (preview, mut texture, mut sprite) in &mut preview_query {
+        let Some(next_size) = preview.next_size else {
+            continue;
+        };
+        let ball_size = next_size.size(&chain);
+        *texture = ball_texture(asset_server.as_deref(), &chain, next_size);
+        sprite.custom_size = Some(Vec2::new(ball_size, ball_size));
     }
 }
+
+// Headless apps have no AssetServer, so balls get an empty texture handle
+fn ball_texture(asset_server: Option<&AssetServer>, chain: &BallChain, variant: BallVariant) -> Handle<Image> {
+    asset_server
+        .map(|asset_server| asset_server.load(variant.sprite_path(chain).to_string()))
+        .unwrap_or_default()
 }
 
 fn spawn_ball_at(
     commands: &mut Commands,
-    asset_server: &AssetServer,
+    asset_server: Option<&AssetServer>,
     variant: BallVariant,
     position: Vec3,
     settings: &Settings,
+    rng: &mut GameRng,
+    chain: &BallChain,
 ) -> Entity {
-    let ball_size = variant.size();
-
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
+    let ball_size = variant.size(chain);
 
     // Very subtle initial velocity
     let velocity = Vec2::new(
-        rng.gen_range(-1.0..1.0),  // Minimal x velocity
-        rng.gen_range(-1.0..1.0)   // Minimal y velocity
+        rng.gameplay.gen_range(-1.0..1.0),  // Minimal x velocity
+        rng.gameplay.gen_range(-1.0..1.0)   // Minimal y velocity
     );
 
     // Very subtle initial rotation
-    let angular_velocity = rng.gen_range(-0.2..0.2);
+    let angular_velocity = rng.gameplay.gen_range(-0.2..0.2);
 
     commands.spawn((
         Ball { 
             variant,
-            glow_phase: rng.gen_range(0.0..std::f32::consts::TAU),
-            color_phase: rng.gen_range(0.0..std::f32::consts::TAU),
-            pulse_phase: rng.gen_range(0.0..std::f32::consts::TAU),
+            glow_phase: rng.cosmetic.gen_range(0.0..std::f32::consts::TAU),
+            color_phase: rng.cosmetic.gen_range(0.0..std::f32::consts::TAU),
+            pulse_phase: rng.cosmetic.gen_range(0.0..std::f32::consts::TAU),
         },
         SpriteBundle {
-            texture: asset_server.load(variant.sprite_path()),
+            texture: ball_texture(asset_server, chain, variant),
             sprite: Sprite {
                 custom_size: Some(Vec2::new(ball_size, ball_size)),
                 ..default()
@@ -1139,7 +1132,7 @@ fn spawn_ball_at(
             angvel: angular_velocity,
         },
         
-        Collider::cuboid(ball_size * 0.49, ball_size * 0.49),
+        chain.tier(variant).shape.collider(ball_size),
         Restitution::coefficient(settings.ball_bounciness),
         Friction::coefficient(settings.ball_friction),
         // Add initial collision effect
@@ -1150,6 +1143,21 @@ fn spawn_ball_at(
     )).id()
 }
 
+// Keeps balls already in play in step with the physics settings
+fn apply_ball_physics_settings(
+    settings: Res<Settings>,
+    mut balls: Query<(&mut Restitution, &mut Friction), With<Ball>>,
+) {
+    for (mut restitution, mut friction) in &mut balls {
+        if restitution.coefficient != settings.ball_bounciness {
+            restitution.coefficient = settings.ball_bounciness;
+        }
+        if friction.coefficient != settings.ball_friction {
+            friction.coefficient = settings.ball_friction;
+        }
+    }
+}
+
 
 // Add new system to handle the effects
 fn handle_collision_effects(
@@ -1175,8 +1183,7 @@ fn handle_collision_effects(
 }
 
 
-fn setup(mut commands: Commands, score: Res<Score>) {
-    // Score display
+fn setup_score_text(mut commands: Commands, score: Res<Score>) {
     commands.spawn((
         ScoreText,
         TextBundle::from_section(
@@ -1194,21 +1201,41 @@ fn setup(mut commands: Commands, score: Res<Score>) {
             ..default()
         }),
     ));
-    // Add 2D camera
-    commands.spawn(Camera2dBundle::default());
+}
+
+fn update_score_text(
+    score: Res<Score>,
+    mut score_text_query: Query<&mut Text, With<ScoreText>>,
+) {
+    if !score.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = score_text_query.get_single_mut() {
+        text.sections[0].value = format!("Score: {}\nHigh Score: {}", score.current, score.high_score);
+    }
+}
+
+// After Startup so a host's own GameCamera is already there
+fn spawn_game_camera(mut commands: Commands, cameras: Query<(), With<GameCamera>>) {
+    if cameras.is_empty() {
+        commands.spawn((GameCamera, Camera2dBundle::default()));
+    }
+}
 
-    // Add ground
+fn setup(mut commands: Commands) {
+    // Add ground. Kinematic so Survival can raise it under the balls
     commands.spawn((
+        Ground,
         SpriteBundle {
             sprite: Sprite {
                 color: Color::srgb(0.2, 0.2, 0.2),
                 custom_size: Some(Vec2::new(500.0, 20.0)),
                 ..default()
             },
-            transform: Transform::from_xyz(0.0, -300.0, 0.0),
+            transform: Transform::from_xyz(0.0, GROUND_Y, 0.0),
             ..default()
         },
-        RigidBody::Fixed,
+        RigidBody::KinematicPositionBased,
         Collider::cuboid(250.0, 10.0),
     ));
 
@@ -1242,58 +1269,48 @@ fn setup(mut commands: Commands, score: Res<Score>) {
         Collider::cuboid(10.0, 300.0),
     ));
 }
-fn spawn_ball(
+#[allow(clippy::too_many_arguments)]
+fn drop_ball(
     mut commands: Commands,
-    mouse: Res<ButtonInput<MouseButton>>,
-    touches: Res<Touches>,
-    camera_q: Query<(&Camera, &GlobalTransform)>,
-    windows: Query<&Window>,
-    asset_server: Res<AssetServer>,
-    mut preview_query: Query<(&mut BallPreview, &mut Handle<Image>, &mut Sprite)>,
-    game_state: Res<State<GameState>>,
-    settings: Res<Settings>
+    mut drop_events: EventReader<DropBall>,
+    mut dropped_events: EventWriter<BallDropped>,
+    asset_server: Option<Res<AssetServer>>,
+    mut preview_query: Query<&mut BallPreview>,
+    settings: Res<Settings>,
+    mut rng: ResMut<GameRng>,
+    mut queue: ResMut<DropQueue>,
+    chain: Res<BallChain>,
 ) {
-    let should_spawn = (mouse.just_released(MouseButton::Left) || touches.any_just_released()) 
-        && *game_state.get() == GameState::Playing;
-
-    if should_spawn {
-        let (camera, camera_transform) = camera_q.single();
-        let window = windows.single();
-        
-        // Get position from either mouse or touch
-        let input_position = if touches.any_just_released() {
-            touches.iter_just_released().next().map(|touch| touch.position())
-        } else {
-            window.cursor_position()
-        }.unwrap_or_default();
-        
-        if let Some(world_position) = camera.viewport_to_world(camera_transform, input_position)
-            .map(|ray| ray.origin.truncate())
-        {
-            // Get the size from preview and spawn that ball
-            if let Ok((mut preview, mut texture, mut sprite)) = preview_query.get_single_mut() {
-                let ball_size = preview.next_size.size();
-                let safe_margin = ball_size / 2.0 + 5.0; // Add 5 pixels extra margin
-                
-                // Clamp x position to prevent wall intersection
-                let x_pos = world_position.x.clamp(-240.0 + safe_margin, 240.0 - safe_margin);
-                
-                spawn_ball_at(
-                    &mut commands,
-                    &asset_server,
-                    preview.next_size,
-                    Vec3::new(x_pos, 300.0, 0.0),
-                    &settings
-                );
-                
-                // Generate next preview
-                preview.next_size = BallVariant::random();
-                let ball_size = preview.next_size.size();
-                
-                // Update preview appearance
-                *texture = asset_server.load(preview.next_size.sprite_path());
-                sprite.custom_size = Some(Vec2::new(ball_size, ball_size));
-            }
+    for drop in drop_events.read() {
+        // Get the size from preview and spawn that ball
+        if let Ok(mut preview) = preview_query.get_single_mut() {
+            let Some(variant) = preview.next_size else {
+                continue;
+            };
+            let ball_size = variant.size(&chain);
+            let safe_margin = ball_size / 2.0 + 5.0; // Add 5 pixels extra margin
+            
+            // Clamp x position to prevent wall intersection
+            let x_pos = drop.x.clamp(-240.0 + safe_margin, 240.0 - safe_margin);
+            
+            let position = Vec3::new(x_pos, 300.0, 0.0);
+            let entity = spawn_ball_at(
+                &mut commands,
+                asset_server.as_deref(),
+                variant,
+                position,
+                &settings,
+                &mut rng,
+                &chain,
+            );
+            dropped_events.send(BallDropped {
+                entity,
+                variant,
+                position,
+            });
+            
+            // Move the queue along
+            preview.next_size = queue.next(&mut rng.queue, &chain, settings.next_queue_length);
         }
     }
 }
@@ -1330,15 +1347,18 @@ fn setup_danger_zone(mut commands: Commands) {
     ));
 }
 
+#[allow(clippy::too_many_arguments)]
 fn check_danger_zone(
     time: Res<Time>,
     mut danger_zone: ResMut<DangerZone>,
     ball_query: Query<&Transform, With<Ball>>,
     mut warning_query: Query<&mut Sprite, With<DangerZoneWarning>>,
     mut next_state: ResMut<NextState<GameState>>,
-    game_sounds: Res<GameSounds>,
-    settings: Res<Settings>,
-    mut commands: Commands,
+    score: Res<Score>,
+    mode: Res<GameMode>,
+    mut danger_events: EventWriter<DangerStarted>,
+    mut game_ended_events: EventWriter<GameEnded>,
+    mut clear_events: EventWriter<ClearTopLayer>,
 ) {
     let balls_in_danger = ball_query
         .iter()
@@ -1353,37 +1373,31 @@ fn check_danger_zone(
         if !danger_zone.is_warning {
             danger_zone.is_warning = true;
             danger_zone.warning_timer.reset();
-            // Play warning sound
-            if settings.sound_enabled {
-                commands.spawn((
-                    AudioBundle {
-                        source: game_sounds.warning.clone(),
-                        settings: PlaybackSettings::DESPAWN,
-                        ..default()
-                    },
-                    GameAudio,
-                ));
-            }
+            danger_events.send(DangerStarted);
         }
         danger_zone.warning_timer.tick(time.delta());
 
-        // Update warning zone visibility
+        // Update warning zone visibility; Zen glows steadily instead of flashing
         if let Ok(mut sprite) = warning_query.get_single_mut() {
-            sprite.color.set_alpha(if danger_zone.show_warning { 0.4 } else { 0.1 });
+            let alpha = match (*mode, danger_zone.show_warning) {
+                (GameMode::Zen, _) => 0.3,
+                (_, true) => 0.4,
+                (_, false) => 0.1,
+            };
+            sprite.color.set_alpha(alpha);
         }
 
-        if danger_zone.warning_timer.finished() {
+        if danger_zone.warning_timer.finished() && *mode == GameMode::Zen {
+            // Nobody loses in Zen; make room and start the timer over
+            clear_events.send(ClearTopLayer);
+            danger_zone.is_warning = false;
+            danger_zone.warning_timer.reset();
+        } else if danger_zone.warning_timer.finished() {
             next_state.set(GameState::GameOver);
-            if settings.sound_enabled {
-                commands.spawn((
-                    AudioBundle {
-                        source: game_sounds.game_over.clone(),
-                        settings: PlaybackSettings::DESPAWN,
-                        ..default()
-                    },
-                    GameAudio,
-                ));
-            }
+            game_ended_events.send(GameEnded {
+                outcome: GameOutcome::Lost,
+                score: score.current,
+            });
         }
     } else {
         danger_zone.is_warning = false;
@@ -1396,11 +1410,15 @@ fn check_danger_zone(
     }
 }
 
-fn setup_game_over(mut commands: Commands) {
+fn setup_game_over(mut commands: Commands, map: Res<InputMap>) {
     commands.spawn((
         GameOverText,
         TextBundle::from_section(
-            "Game Over!\nPress SPACE to restart",
+            format!(
+                "Game Over!\nPress {} to restart\nor {} for the menu",
+                map.hint(Action::Restart),
+                map.hint(Action::Menu),
+            ),
             TextStyle {
                 font_size: 50.0,
                 color: Color::WHITE,
@@ -1422,8 +1440,10 @@ fn setup_game_over(mut commands: Commands) {
 fn update_ball_effects(
     time: Res<Time>,
     settings: Res<Settings>,
+    mode: Res<GameMode>,
     mut query: Query<(&mut Ball, &mut Transform, &mut Sprite)>,
 ) {
+    let settings = settings.for_mode(*mode);
     for (mut ball, mut transform, mut sprite) in query.iter_mut() {
         // Update phases
         ball.glow_phase += settings.glow_speed * time.delta_seconds();
@@ -1449,15 +1469,28 @@ fn update_ball_effects(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn restart_game(
     mut commands: Commands,
+    mut restart_events: EventReader<RestartGame>,
     balls: Query<Entity, With<Ball>>,
     game_over_text: Query<Entity, With<GameOverText>>,
     win_text: Query<Entity, With<WinText>>,
+    mut preview_query: Query<&mut BallPreview>,
+    mut rng: ResMut<GameRng>,
+    mut queue: ResMut<DropQueue>,
+    mode: Res<GameMode>,
+    settings: Res<Settings>,
+    chain: Res<BallChain>,
     mut score: ResMut<Score>,
-    mut score_text_query: Query<&mut Text, With<ScoreText>>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut started_events: EventWriter<GameStarted>,
 ) {
+    // Several handlers can fire on the same key press; restart only once
+    if restart_events.read().count() == 0 {
+        return;
+    }
+
     // Remove all balls
     for entity in balls.iter() {
         commands.entity(entity).despawn();
@@ -1473,195 +1506,226 @@ fn restart_game(
         commands.entity(entity).despawn();
     }
 
+    // Restart the random streams so a fixed seed replays the same game, and
+    // everyone playing the Daily today gets the same balls
+    if *mode == GameMode::Daily {
+        rng.restart_with(daily_seed(utc_day()));
+    } else {
+        rng.restart();
+    }
+    queue.clear();
+    if let Ok(mut preview) = preview_query.get_single_mut() {
+        preview.next_size = queue.next(&mut rng.queue, &chain, settings.next_queue_length);
+    }
+
     // Reset score and state
     score.current = 0;
-    if let Ok(mut text) = score_text_query.get_single_mut() {
-        text.sections[0].value = format!("Score: {}\nHigh Score: {}", score.current, score.high_score);
-    }
     next_state.set(GameState::Playing);
+    started_events.send(GameStarted);
 }
 
 fn handle_game_over(
-    mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    balls: Query<Entity, With<Ball>>,
-    game_over_text: Query<Entity, With<GameOverText>>,
-    win_text: Query<Entity, With<WinText>>,
-    mut score: ResMut<Score>,
-    mut score_text_query: Query<&mut Text, With<ScoreText>>,
+    actions: Actions,
+    mut restart_events: EventWriter<RestartGame>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::KeyR) {
-        restart_game(
-            commands,
-            balls,
-            game_over_text,
-            win_text,
-            score,
-            score_text_query,
-            next_state,
-        );
+    if actions.just_pressed(Action::Drop) || actions.just_pressed(Action::Restart) {
+        restart_events.send(RestartGame);
     }
 }
 
 // Add a new system to handle global restart
 fn handle_global_restart(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
+    mut restart_events: EventWriter<RestartGame>,
+) {
+    if actions.just_pressed(Action::Restart) {
+        restart_events.send(RestartGame);
+    }
+}
+
+
+#[allow(clippy::too_many_arguments)]
+fn apply_merges(
     mut commands: Commands,
-    balls: Query<Entity, With<Ball>>,
-    game_over_text: Query<Entity, With<GameOverText>>,
-    win_text: Query<Entity, With<WinText>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut merges: EventReader<MergeEvent>,
+    mut merged_events: EventWriter<BallsMerged>,
+    mut game_ended_events: EventWriter<GameEnded>,
     mut score: ResMut<Score>,
-    mut score_text_query: Query<&mut Text, With<ScoreText>>,
+    settings: Res<Settings>,
+    mut rng: ResMut<GameRng>,
+    chain: Res<BallChain>,
+    mut stats: ResMut<Stats>,
+    mut combo_tracker: ResMut<ComboTracker>,
+    combo_links: Query<&ComboLink>,
+    mut blitz: ResMut<BlitzClock>,
+    mode: Res<GameMode>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyR) {
-        restart_game(
-            commands,
-            balls,
-            game_over_text,
-            win_text,
-            score,
-            score_text_query,
-            next_state,
-        );
+    for merge in merges.read() {
+        let Some(next_variant) = merge.variant.next_variant(&chain) else {
+            continue;
+        };
+
+        // Only despawn if the entities still exist
+        if let Some(mut entity1) = commands.get_entity(merge.older) {
+            entity1.despawn();
+        }
+        if let Some(mut entity2) = commands.get_entity(merge.younger) {
+            entity2.despawn();
+        }
+
+        // Score comes from the merged tier in the chain, times the combo and
+        // any Blitz fast-merge multiplier. Survival scores time alone
+        let combo = combo_tracker.record(combo_links.get(merge.older).into_iter().chain(combo_links.get(merge.younger)));
+        let points = match *mode {
+            GameMode::Survival => 0,
+            _ => chain.tier(merge.variant).score * combo * blitz.merge_multiplier(),
+        };
+        score.current += points;
+        score.high_score = score.high_score.max(score.current);
+        stats.total_merges += 1;
+
+        let new_ball = spawn_ball_at(&mut commands, asset_server.as_deref(), next_variant, merge.position, &settings, &mut rng, &chain);
+        merged_events.send(BallsMerged {
+            entity: new_ball,
+            from: merge.variant,
+            into: next_variant,
+            position: merge.position,
+            points,
+            combo,
+        });
+
+        if chain.tier(next_variant).wins_game {
+            next_state.set(GameState::Win);
+            game_ended_events.send(GameEnded {
+                outcome: GameOutcome::Won,
+                score: score.current,
+            });
+        } else {
+            commands.entity(new_ball).insert(combo_tracker.link(combo));
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn merge_effects(
+    mut commands: Commands,
+    mut merged_events: EventReader<BallsMerged>,
+    mut particle_count: ResMut<ParticleCount>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut rng: ResMut<GameRng>,
+    chain: Res<BallChain>,
+) {
+    let settings = settings.for_mode(*mode);
+    for merged in merged_events.read() {
+        let wins_game = chain.tier(merged.into).wins_game;
+
+        // Add screen shake effect, gentler for the winning ball.
+        // Zen keeps the screen still.
+        let shake = if wins_game { 0.3 } else { 0.5 };
+        if *mode != GameMode::Zen {
+            commands.insert_resource(ScreenShakeState {
+                trauma: merged.from.size(&chain) / BASE_BALL_SIZE * shake,
+            });
+        }
+
+        // Add explosion effect
+        let explosion_color = Color::srgba(1.0, 0.5, 0.0, 1.0);
+        spawn_explosion(&mut commands, merged.position, explosion_color, &settings, &mut particle_count, &mut rng.cosmetic);
+
+        if merged.combo > 1 && !wins_game {
+            spawn_combo_label(&mut commands, merged.position, merged.combo);
+        }
     }
 }
 
+// A soft burst where each ball popped in Zen mode
+fn clear_effects(
+    mut commands: Commands,
+    mut cleared_events: EventReader<BallCleared>,
+    mut particle_count: ResMut<ParticleCount>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut rng: ResMut<GameRng>,
+) {
+    let settings = settings.for_mode(*mode);
+    for cleared in cleared_events.read() {
+        let color = Color::srgba(0.6, 0.8, 1.0, 1.0);
+        spawn_explosion(&mut commands, cleared.position, color, &settings, &mut particle_count, &mut rng.cosmetic);
+    }
+}
 
-fn handle_ball_collisions(
+#[allow(clippy::too_many_arguments)]
+fn play_event_sounds(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    rapier_context: Res<RapierContext>, 
-    query: Query<(Entity, &Ball, &Transform)>,
-    mut score_text_query: Query<&mut Text, With<ScoreText>>,
-    mut particle_count: ResMut<ParticleCount>,
-    mut score: ResMut<Score>,
     game_sounds: Res<GameSounds>,
     settings: Res<Settings>,
-    mut next_state: ResMut<NextState<GameState>>,
+    chain: Res<BallChain>,
+    mut merged_events: EventReader<BallsMerged>,
+    mode: Res<GameMode>,
+    mut danger_events: EventReader<DangerStarted>,
+    mut game_ended_events: EventReader<GameEnded>,
+    mut cleared_events: EventReader<BallCleared>,
 ) {
-    for pair in rapier_context.contact_pairs() {
-        let entity1 = pair.collider1();
-        let entity2 = pair.collider2();
-
-        if let (Ok((e1, ball1, transform1)), Ok((e2, ball2, transform2))) = 
-            (query.get(entity1), query.get(entity2)) 
-        {
-
-            if ball1.variant == ball2.variant {
-                let position = (transform1.translation + transform2.translation) / 2.0;
-                    
-                if let Some(next_variant) = ball1.variant.next_variant() {
-                    // Only despawn if the entities still exist
-                    if let Some(mut entity1) = commands.get_entity(e1) {
-                        entity1.despawn();
-                    }
-                    if let Some(mut entity2) = commands.get_entity(e2) {
-                        entity2.despawn();
-                    }
-
-                    // Calculate score based on ball order
-                    // Base score is 10 points, doubled for each order level
-                    let score_value = if ball1.variant == BallVariant::Win {
-                        5000 // Special case for win
-                    } else {
-                        (10.0 * 2.0f32.powf((ball1.variant.order() - 1) as f32)) as u32
-                    };
-                    
-                    score.current += score_value;
-                    score.high_score = score.high_score.max(score.current);
-                    
-                    // Update score display
-                    if let Ok(mut text) = score_text_query.get_single_mut() {
-                        text.sections[0].value = format!("Score: {}\nHigh Score: {}", score.current, score.high_score);
-                    }
-
-                    if next_variant == BallVariant::Win {
-                        // Create the Ultimate ball
-                        let new_ball = spawn_ball_at(&mut commands, &asset_server, next_variant, position, &settings);
-                        commands.entity(new_ball).insert(CollisionEffect {
-                            timer: Timer::from_seconds(0.3, TimerMode::Once),
-                            initial_scale: Vec3::ONE,
-                        });
-                            
-                        // Trigger win effects
-                        // Add screen shake
-                        commands.insert_resource(ScreenShakeState {
-                            trauma: ball1.variant.size() / BASE_BALL_SIZE * 0.3,
-                            decay: 2.0,
-                        });
-                        // Spawn enhanced explosion
-                        let explosion_color = Color::srgba(1.0, 0.5, 0.0, 1.0);
-                        spawn_explosion(&mut commands, position, explosion_color, &settings, &mut particle_count);
-                    
-                        if settings.sound_enabled {
-                            commands.spawn((
-                                AudioBundle {
-                                    source: game_sounds.pop.clone(),
-                                    settings: PlaybackSettings::DESPAWN,
-                                    ..default()
-                                },
-                                GameAudio,
-                            ));
-                        }
-                            
-                        // Trigger win state
-                        next_state.set(GameState::Win);
-                    } else {
-                        // Normal combination
-                        let new_ball = spawn_ball_at(&mut commands, &asset_server, next_variant, position, &settings);
-
-                        // Add screen shake effect
-                        let trauma = ball1.variant.size() / BASE_BALL_SIZE * 0.5; // Reduced multiplier
-                        // println!("Setting shake trauma: {:.3} for ball size: {}", trauma, ball1.variant.size());
-                        commands.insert_resource(ScreenShakeState {
-                            trauma,
-                            decay: 1.5, // Slower decay
-                        });
-
-                        // Add explosion effect
-                        let explosion_color = Color::srgba(1.0, 0.5, 0.0, 1.0);
-                        spawn_explosion(&mut commands, position, explosion_color, &settings, &mut particle_count);
-                        
-                        commands.entity(new_ball).insert(CollisionEffect {
-                            timer: Timer::from_seconds(0.3, TimerMode::Once),
-                            initial_scale: Vec3::ONE,
-                        });
-                            
-                        if settings.sound_enabled {
-                            commands.spawn((
-                                AudioBundle {
-                                    source: game_sounds.collision.clone(),
-                                    settings: PlaybackSettings::DESPAWN,
-                                    ..default()
-                                },
-                                GameAudio,
-                            ));
-                        }
-
-                        commands.entity(new_ball).insert(CollisionEffect {
-                            timer: Timer::from_seconds(0.3, TimerMode::Once),
-                            initial_scale: Vec3::ONE,
-                        });
-
-                    }
-                }
+    let mut sounds = Vec::new();
+    for merged in merged_events.read() {
+        // Tiers can override the default merge sound
+        let tier = chain.tier(merged.into);
+        sounds.push(match (&tier.sound, tier.wins_game) {
+            (Some(path), _) => asset_server.load(path.clone()),
+            (None, true) => game_sounds.pop.clone(),
+            (None, false) => game_sounds.collision.clone(),
+        });
+    }
+    // Zen skips the warning and pops once however many balls went
+    let warnings = danger_events.read().count();
+    if *mode != GameMode::Zen && warnings > 0 {
+        sounds.push(game_sounds.warning.clone());
+    }
+    if cleared_events.read().count() > 0 {
+        sounds.push(game_sounds.pop.clone());
+    }
+    sounds.extend(game_ended_events.read()
+        .filter(|ended| ended.outcome != GameOutcome::Won)
+        .map(|_| game_sounds.game_over.clone()));
 
-            }
-        }
+    if !settings.sound_enabled {
+        return;
+    }
+    let volume = bevy::audio::Volume::new(settings.for_mode(*mode).volume);
+    for source in sounds {
+        commands.spawn((
+            AudioBundle {
+                source,
+                settings: PlaybackSettings::DESPAWN.with_volume(volume),
+            },
+            GameAudio,
+        ));
     }
 }
 
 
-#[derive(Component)]
+#[derive(Component, Default)]
 struct WinText;
 
-fn setup_win_screen(mut commands: Commands) {
+fn despawn_all<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn setup_win_screen(mut commands: Commands, map: Res<InputMap>) {
     commands.spawn((
         WinText,
         TextBundle::from_section(
-            "You Won!\nPress SPACE to play again",
+            format!(
+                "You Won!\nPress {} to play again\nor {} for the menu",
+                map.hint(Action::Restart),
+                map.hint(Action::Menu),
+            ),
             TextStyle {
                 font_size: 50.0,
                 color: Color::srgb(1.0, 0.84, 0.0), // Gold color in RGB
@@ -1681,30 +1745,10 @@ fn setup_win_screen(mut commands: Commands) {
 }
 
 fn handle_win_screen(
-    mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    balls: Query<Entity, With<Ball>>,
-    win_text: Query<Entity, With<WinText>>,
-    mut score: ResMut<Score>,
-    mut score_text_query: Query<&mut Text, With<ScoreText>>,
+    actions: Actions,
+    mut restart_events: EventWriter<RestartGame>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) {
-        // Remove all balls
-        for entity in balls.iter() {
-            commands.entity(entity).despawn();
-        }
-        
-        // Remove win text
-        for entity in win_text.iter() {
-            commands.entity(entity).despawn();
-        }
-        
-        // Reset score and state
-        score.current = 0;
-        if let Ok(mut text) = score_text_query.get_single_mut() {
-            text.sections[0].value = format!("Score: {}\nHigh Score: {}", score.current, score.high_score);
-        }
-        next_state.set(GameState::Playing);
+    if actions.just_pressed(Action::Drop) {
+        restart_events.send(RestartGame);
     }
 }
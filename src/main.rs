@@ -1,5 +1,3 @@
-use ball_drop_rust;
-
 fn main() {
     ball_drop_rust::main();
 }
@@ -0,0 +1,318 @@
+use bevy::{input::touch::Touches, prelude::*};
+
+use crate::{
+    ball_texture,
+    widgets::{hover_color, spawn_button, MENU_BUTTON_SIZE},
+    Action, Actions, BallChain, BallVariant, GameMode, GameState, PuzzleLevels, PuzzleRun, RestartGame,
+    SettingsReturnState, Stats,
+};
+
+// Seconds for the highlight to travel along the chain once
+const CHAIN_WAVE_PERIOD: f32 = 3.0;
+
+#[derive(Resource, Default, PartialEq, Clone, Copy)]
+enum MenuPage {
+    #[default]
+    Main,
+    Modes,
+    Puzzles,
+    Stats,
+    Credits,
+}
+
+#[derive(Component, PartialEq, Clone, Copy)]
+enum MenuButton {
+    // Plays the current mode
+    Play,
+    Mode(GameMode),
+    // Plays a puzzle level, by index into PuzzleLevels
+    Puzzle(usize),
+    Page(MenuPage),
+    Settings,
+}
+
+#[derive(Component)]
+struct MainMenu;
+
+// The part of the menu that changes with MenuPage
+#[derive(Component)]
+struct MenuPageContent;
+
+#[derive(Component)]
+struct ChainPreviewBall {
+    index: usize,
+}
+
+/// A title screen the game starts on, with the ball chain on show and pages
+/// for modes, puzzle levels, stats and credits. Players return to it from the
+/// game over and win screens with M.
+pub struct BallDropMenuPlugin;
+
+impl Plugin for BallDropMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_state(GameState::MainMenu)
+            .init_resource::<MenuPage>()
+            .add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
+            .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu)
+            .add_systems(Update, (
+                menu_button_interaction,
+                show_menu_page.run_if(resource_changed::<MenuPage>),
+                animate_chain_preview,
+            ).chain().run_if(in_state(GameState::MainMenu)))
+            .add_systems(Update, return_to_menu.run_if(in_state(GameState::GameOver).or_else(in_state(GameState::Win))));
+    }
+}
+
+fn setup_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    chain: Res<BallChain>,
+    stats: Res<Stats>,
+    levels: Res<PuzzleLevels>,
+    mut page: ResMut<MenuPage>,
+) {
+    *page = MenuPage::Main;
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(20.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.95)),
+                ..default()
+            },
+            MainMenu,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle::from_section(
+                "Ball Drop",
+                TextStyle {
+                    font_size: 56.0,
+                    color: Color::srgb(1.0, 0.84, 0.0),
+                    ..default()
+                },
+            ));
+
+            // Every tier, smallest first
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    max_width: Val::Px(460.0),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|row| {
+                for index in 0..chain.tiers.len() {
+                    // Grow a little along the chain without letting the big ones take over
+                    let size = 22.0 + 3.0 * index as f32;
+                    row.spawn((
+                        ImageBundle {
+                            image: UiImage::new(ball_texture(Some(&asset_server), &chain, BallVariant(index))),
+                            style: Style {
+                                width: Val::Px(size),
+                                height: Val::Px(size),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        ChainPreviewBall { index },
+                    ));
+                }
+            });
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                MenuPageContent,
+            ))
+            .with_children(|content| spawn_page(content, MenuPage::Main, &stats, &levels));
+        });
+}
+
+fn spawn_page(parent: &mut ChildBuilder, page: MenuPage, stats: &Stats, levels: &PuzzleLevels) {
+    match page {
+        MenuPage::Main => {
+            spawn_menu_button(parent, "Play", MenuButton::Play);
+            spawn_menu_button(parent, "Modes", MenuButton::Page(MenuPage::Modes));
+            spawn_menu_button(parent, "Settings", MenuButton::Settings);
+            spawn_menu_button(parent, "Stats", MenuButton::Page(MenuPage::Stats));
+            spawn_menu_button(parent, "Credits", MenuButton::Page(MenuPage::Credits));
+        }
+        MenuPage::Modes => {
+            for mode in GameMode::ALL {
+                spawn_menu_text(parent, &format!("{}: {}", mode.label(), mode.description()));
+                // Puzzles start from a level, picked on their own page
+                let button = match mode {
+                    GameMode::Puzzle => MenuButton::Page(MenuPage::Puzzles),
+                    mode => MenuButton::Mode(mode),
+                };
+                spawn_menu_button(parent, mode.label(), button);
+            }
+            spawn_menu_button(parent, "Back", MenuButton::Page(MenuPage::Main));
+        }
+        MenuPage::Puzzles => {
+            for (index, level) in levels.levels.iter().enumerate() {
+                let stars = match stats.puzzle_stars.get(&level.name) {
+                    Some(stars) => format!("{stars}/3"),
+                    None => "-".to_string(),
+                };
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_menu_button(row, &level.name, MenuButton::Puzzle(index));
+                    spawn_menu_text(row, &stars);
+                });
+            }
+            spawn_menu_button(parent, "Back", MenuButton::Page(MenuPage::Modes));
+        }
+        MenuPage::Stats => {
+            let minutes = (stats.time_played_secs / 60.0) as u64;
+            let mode_bests: String = GameMode::ALL
+                .iter()
+                .filter(|mode| !matches!(mode, GameMode::Classic | GameMode::Puzzle))
+                .map(|mode| format!("{} best: {}\n", mode.label(), stats.best_score(*mode)))
+                .collect();
+            let puzzle_stars: u32 = stats.puzzle_stars.values().sum();
+            spawn_menu_text(parent, &format!(
+                "High score: {}\n{mode_bests}Puzzle stars: {puzzle_stars}\nGames played: {}\nTotal merges: {}\nBiggest ball: {}\nBest combo: {}\nTime played: {}h {}m",
+                stats.high_score,
+                stats.games_played,
+                stats.total_merges,
+                stats.highest_variant.as_deref().unwrap_or("-"),
+                stats.best_combo,
+                minutes / 60,
+                minutes % 60,
+            ));
+            spawn_menu_button(parent, "Back", MenuButton::Page(MenuPage::Main));
+        }
+        MenuPage::Credits => {
+            spawn_menu_text(parent, "Ball Drop\nBuilt with Bevy and Rapier\nThanks for playing!");
+            spawn_menu_button(parent, "Back", MenuButton::Page(MenuPage::Main));
+        }
+    }
+}
+
+fn spawn_menu_text(parent: &mut ChildBuilder, text: &str) {
+    parent.spawn(TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size: 20.0,
+            color: Color::WHITE,
+            ..default()
+        },
+    ).with_text_justify(JustifyText::Center));
+}
+
+fn spawn_menu_button(parent: &mut ChildBuilder, label: &str, button: MenuButton) {
+    spawn_button(parent, label, MENU_BUTTON_SIZE, button);
+}
+
+// Buttons act on release, so the click that starts a game is over before
+// gameplay input sees it and doesn't drop a ball
+#[allow(clippy::too_many_arguments)]
+fn menu_button_interaction(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut buttons: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut page: ResMut<MenuPage>,
+    mut mode: ResMut<GameMode>,
+    mut puzzle_run: ResMut<PuzzleRun>,
+    mut settings_return: ResMut<SettingsReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut restart_events: EventWriter<RestartGame>,
+) {
+    let released = mouse.just_released(MouseButton::Left) || touches.any_just_released();
+    for (interaction, button, mut color) in &mut buttons {
+        *color = hover_color(interaction);
+        if *interaction == Interaction::Pressed || !released {
+            continue;
+        }
+        match *button {
+            MenuButton::Play => {
+                restart_events.send(RestartGame);
+            }
+            MenuButton::Mode(selected) => {
+                *mode = selected;
+                restart_events.send(RestartGame);
+            }
+            MenuButton::Puzzle(level) => {
+                *mode = GameMode::Puzzle;
+                puzzle_run.level = level;
+                restart_events.send(RestartGame);
+            }
+            MenuButton::Page(target) => {
+                *page = target;
+            }
+            MenuButton::Settings => {
+                settings_return.0 = GameState::MainMenu;
+                next_state.set(GameState::Settings);
+            }
+        }
+    }
+}
+
+fn show_menu_page(
+    mut commands: Commands,
+    page: Res<MenuPage>,
+    stats: Res<Stats>,
+    levels: Res<PuzzleLevels>,
+    content: Query<Entity, With<MenuPageContent>>,
+) {
+    for entity in &content {
+        commands.entity(entity)
+            .despawn_descendants()
+            .with_children(|content| spawn_page(content, *page, &stats, &levels));
+    }
+}
+
+// Virtual time is paused behind the menu
+fn animate_chain_preview(
+    time: Res<Time<Real>>,
+    chain: Res<BallChain>,
+    mut balls: Query<(&ChainPreviewBall, &mut Transform)>,
+) {
+    // A highlight runs up the chain, showing what merges into what
+    let wave = (time.elapsed_seconds() / CHAIN_WAVE_PERIOD).fract() * chain.tiers.len() as f32;
+    for (ball, mut transform) in &mut balls {
+        let distance = (wave - ball.index as f32).abs();
+        let scale = 1.0 + 0.35 * (1.0 - distance).max(0.0);
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+fn return_to_menu(actions: Actions, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.just_pressed(Action::Menu) {
+        next_state.set(GameState::MainMenu);
+    }
+}
+
+fn cleanup_main_menu(mut commands: Commands, menu: Query<Entity, With<MainMenu>>) {
+    for entity in &menu {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::{prelude::*, utils::HashSet};
+use bevy_rapier2d::prelude::*;
+
+use crate::{Ball, BallChain, BallVariant};
+
+/// Two touching balls of the same variant that merge this frame. Each ball is
+/// in at most one `MergeEvent` per frame; `older` was spawned first.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MergeEvent {
+    pub older: Entity,
+    pub younger: Entity,
+    /// The variant of both balls, before merging
+    pub variant: BallVariant,
+    /// Midpoint of the two balls, where the merged ball appears
+    pub position: Vec3,
+}
+
+// Spawn order of a ball, for breaking ties between candidate merges
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct SpawnOrder(u64);
+
+#[derive(Resource, Default)]
+struct NextSpawnOrder(u64);
+
+/// Turns Rapier contacts into `MergeEvent`s in `find_merges`, so a ball
+/// touching two same-variant neighbours only merges with one of them.
+pub(crate) struct MergePlugin;
+
+impl Plugin for MergePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NextSpawnOrder>()
+            .add_event::<MergeEvent>()
+            .add_systems(Update, number_new_balls.before(find_merges));
+    }
+}
+
+fn number_new_balls(
+    mut commands: Commands,
+    mut next_order: ResMut<NextSpawnOrder>,
+    new_balls: Query<Entity, (With<Ball>, Without<SpawnOrder>)>,
+) {
+    // Balls spawned in the same frame are numbered in entity order
+    let mut new_balls: Vec<Entity> = new_balls.iter().collect();
+    new_balls.sort();
+    for entity in new_balls {
+        // Zen mode can pop a ball in the same frame it was numbered
+        commands.entity(entity).try_insert(SpawnOrder(next_order.0));
+        next_order.0 += 1;
+    }
+}
+
+/// Collects every touching same-variant pair, then claims them closest pair
+/// first (ties go to the pair with the oldest ball) so each ball merges at
+/// most once per frame.
+pub(crate) fn find_merges(
+    rapier_context: Res<RapierContext>,
+    chain: Res<BallChain>,
+    balls: Query<(&Ball, &Transform, Option<&SpawnOrder>)>,
+    mut merges: EventWriter<MergeEvent>,
+) {
+    // Balls not numbered yet were spawned this frame, so they're the youngest
+    let order = |order: Option<&SpawnOrder>| order.copied().unwrap_or(SpawnOrder(u64::MAX));
+
+    let mut candidates = Vec::new();
+    for pair in rapier_context.contact_pairs() {
+        let (entity1, entity2) = (pair.collider1(), pair.collider2());
+        let (Ok((ball1, transform1, order1)), Ok((ball2, transform2, order2))) =
+            (balls.get(entity1), balls.get(entity2))
+        else {
+            continue;
+        };
+        if ball1.variant != ball2.variant || ball1.variant.next_variant(&chain).is_none() {
+            continue;
+        }
+
+        let (older, younger) = if (order(order1), entity1) <= (order(order2), entity2) {
+            ((order(order1), entity1), (order(order2), entity2))
+        } else {
+            ((order(order2), entity2), (order(order1), entity1))
+        };
+        candidates.push(MergeCandidate {
+            distance: transform1.translation.truncate().distance(transform2.translation.truncate()),
+            older,
+            younger,
+            variant: ball1.variant,
+            position: (transform1.translation + transform2.translation) / 2.0,
+        });
+    }
+
+    merges.send_batch(claim_merges(candidates));
+}
+
+// A touching same-variant pair, before claim_merges picks which ones happen
+struct MergeCandidate {
+    distance: f32,
+    older: (SpawnOrder, Entity),
+    younger: (SpawnOrder, Entity),
+    variant: BallVariant,
+    position: Vec3,
+}
+
+fn claim_merges(mut candidates: Vec<MergeCandidate>) -> Vec<MergeEvent> {
+    candidates.sort_by(|a, b| {
+        a.distance.total_cmp(&b.distance)
+            .then(a.older.cmp(&b.older))
+            .then(a.younger.cmp(&b.younger))
+    });
+
+    let mut claimed = HashSet::new();
+    let mut merges = Vec::new();
+    for candidate in candidates {
+        let (older, younger) = (candidate.older.1, candidate.younger.1);
+        if claimed.contains(&older) || claimed.contains(&younger) {
+            continue;
+        }
+        claimed.insert(older);
+        claimed.insert(younger);
+        merges.push(MergeEvent {
+            older,
+            younger,
+            variant: candidate.variant,
+            position: candidate.position,
+        });
+    }
+    merges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(distance: f32, older: u32, younger: u32) -> MergeCandidate {
+        MergeCandidate {
+            distance,
+            older: (SpawnOrder(older.into()), Entity::from_raw(older)),
+            younger: (SpawnOrder(younger.into()), Entity::from_raw(younger)),
+            variant: BallVariant(0),
+            position: Vec3::ZERO,
+        }
+    }
+
+    fn pairs(merges: &[MergeEvent]) -> Vec<(u32, u32)> {
+        merges.iter().map(|merge| (merge.older.index(), merge.younger.index())).collect()
+    }
+
+    #[test]
+    fn closest_pair_claims_the_shared_ball() {
+        // Ball 1 touches both 0 and 2, and is nearer to 2
+        let merges = claim_merges(vec![candidate(40.0, 0, 1), candidate(30.0, 1, 2)]);
+        assert_eq!(pairs(&merges), [(1, 2)]);
+    }
+
+    #[test]
+    fn equal_distances_go_to_the_oldest_ball() {
+        let merges = claim_merges(vec![candidate(30.0, 1, 2), candidate(30.0, 0, 1)]);
+        assert_eq!(pairs(&merges), [(0, 1)]);
+
+        // Same older ball: the older of the two partners wins
+        let merges = claim_merges(vec![candidate(30.0, 0, 3), candidate(30.0, 0, 2)]);
+        assert_eq!(pairs(&merges), [(0, 2)]);
+    }
+
+    #[test]
+    fn separate_pairs_all_merge() {
+        let merges = claim_merges(vec![candidate(30.0, 0, 1), candidate(35.0, 2, 3)]);
+        assert_eq!(pairs(&merges), [(0, 1), (2, 3)]);
+    }
+}
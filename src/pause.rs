@@ -0,0 +1,185 @@
+use bevy::{input::touch::Touches, prelude::*, window::WindowFocused};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    widgets::{hover_color, spawn_button, MENU_BUTTON_SIZE},
+    Action, GameState, InputMap, SettingsReturnState,
+};
+
+// Set by the page when the tab is hidden, picked up on the next frame
+#[cfg(target_arch = "wasm32")]
+static PAGE_HIDDEN: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// True while the game is paused, the settings overlay is open, or the main
+/// menu is showing over the board.
+pub(crate) fn is_frozen(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::Paused | GameState::Settings | GameState::MainMenu)
+}
+
+/// Stops physics and virtual time whenever `is_frozen`. Everything driven by
+/// `Time` (danger zone, particles, shake, combos) waits with it.
+pub(crate) struct FreezePlugin;
+
+impl Plugin for FreezePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, freeze_game.run_if(state_changed::<GameState>));
+    }
+}
+
+/// The pause overlay, plus pausing automatically when the window loses focus
+/// or the browser tab is hidden.
+pub struct BallDropPausePlugin;
+
+impl Plugin for BallDropPausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, watch_page_visibility)
+            .add_systems(OnEnter(GameState::Playing), forget_page_hidden)
+            .add_systems(OnEnter(GameState::Paused), setup_pause_menu)
+            .add_systems(OnExit(GameState::Paused), cleanup_pause_menu)
+            .add_systems(Update, pause_menu_interaction.run_if(in_state(GameState::Paused)))
+            .add_systems(Update, auto_pause.run_if(in_state(GameState::Playing)));
+    }
+}
+
+#[derive(Component)]
+struct PauseMenu;
+
+#[derive(Component, Clone, Copy)]
+enum PauseButton {
+    Resume,
+    Settings,
+    MainMenu,
+}
+
+fn freeze_game(
+    state: Res<State<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
+    mut rapier_config: ResMut<RapierConfiguration>,
+) {
+    let frozen = is_frozen(state);
+    if frozen && !time.is_paused() {
+        time.pause();
+    } else if !frozen && time.is_paused() {
+        time.unpause();
+    }
+    if rapier_config.physics_pipeline_active == frozen {
+        rapier_config.physics_pipeline_active = !frozen;
+    }
+}
+
+fn auto_pause(
+    mut focus_events: EventReader<WindowFocused>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let lost_focus = focus_events.read().any(|event| !event.focused);
+
+    #[cfg(target_arch = "wasm32")]
+    let lost_focus = PAGE_HIDDEN.swap(false, std::sync::atomic::Ordering::SeqCst) || lost_focus;
+
+    if lost_focus {
+        next_state.set(GameState::Paused);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn watch_page_visibility() {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let document = || web_sys::window().and_then(|window| window.document());
+    let Some(page) = document() else {
+        return;
+    };
+    let on_change = Closure::<dyn FnMut()>::new(move || {
+        if document().is_some_and(|page| page.hidden()) {
+            PAGE_HIDDEN.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    });
+    if let Err(err) = page.add_event_listener_with_callback("visibilitychange", on_change.as_ref().unchecked_ref()) {
+        warn!("Can't watch page visibility: {err:?}");
+    }
+    // Lives as long as the page
+    on_change.forget();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn watch_page_visibility() {}
+
+// The tab may have been hidden while a menu was open, which shouldn't pause
+// the game the moment it starts
+#[cfg(target_arch = "wasm32")]
+fn forget_page_hidden() {
+    PAGE_HIDDEN.store(false, std::sync::atomic::Ordering::SeqCst);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn forget_page_hidden() {}
+
+fn setup_pause_menu(mut commands: Commands, map: Res<InputMap>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                ..default()
+            },
+            PauseMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                format!("Paused\nPress {} to resume", map.hint(Action::Pause)),
+                TextStyle {
+                    font_size: 40.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ).with_text_justify(JustifyText::Center));
+
+            for (label, button) in [
+                ("Resume", PauseButton::Resume),
+                ("Settings", PauseButton::Settings),
+                ("Main Menu", PauseButton::MainMenu),
+            ] {
+                spawn_button(parent, label, MENU_BUTTON_SIZE, button);
+            }
+        });
+}
+
+// Acts on release so resuming doesn't also drop a ball
+fn pause_menu_interaction(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut buttons: Query<(&Interaction, &PauseButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut settings_return: ResMut<SettingsReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let released = mouse.just_released(MouseButton::Left) || touches.any_just_released();
+    for (interaction, button, mut color) in &mut buttons {
+        *color = hover_color(interaction);
+        if *interaction == Interaction::Pressed || !released {
+            continue;
+        }
+        match button {
+            PauseButton::Resume => next_state.set(GameState::Playing),
+            PauseButton::Settings => {
+                settings_return.0 = GameState::Paused;
+                next_state.set(GameState::Settings);
+            }
+            PauseButton::MainMenu => next_state.set(GameState::MainMenu),
+        }
+    }
+}
+
+fn cleanup_pause_menu(mut commands: Commands, menu: Query<Entity, With<PauseMenu>>) {
+    for entity in &menu {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,404 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::ron_asset::{RonAsset, RonAssetHandle, RonAssetPlugin};
+use crate::widgets::{spawn_results_line, spawn_top_hud};
+use crate::{
+    restart_game, spawn_ball_at, Ball, BallChain, BallDropped, BallPreview, BallVariant, BallsMerged,
+    DropQueue, GameEnded, GameMode, GameOutcome, GameOverText, GameRng, GameStarted, GameState,
+    Score, Settings, Stats, WinText,
+};
+
+// Once the last ball is dropped, how long the board gets to stop merging
+// before the level counts as lost
+const SETTLE_TIME: f32 = 3.0;
+
+/// What a level asks for, naming a tier of the active `BallChain`.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub enum PuzzleGoal {
+    /// Merge up a ball of this tier
+    Create(String),
+    /// Leave no ball of this tier in the container
+    Clear(String),
+}
+
+impl PuzzleGoal {
+    fn tier(&self) -> &str {
+        match self {
+            PuzzleGoal::Create(tier) | PuzzleGoal::Clear(tier) => tier,
+        }
+    }
+
+    pub fn describe(&self, drops: usize) -> String {
+        match self {
+            PuzzleGoal::Create(tier) => format!("Make a {tier} ball in {drops} drops"),
+            PuzzleGoal::Clear(tier) => format!("Clear every {tier} ball in {drops} drops"),
+        }
+    }
+}
+
+/// A ball already in the container when a level starts.
+#[derive(Deserialize, Clone, Debug)]
+pub struct PlacedBall {
+    pub tier: String,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// One level, as written in a `.puzzles.ron` file.
+#[derive(Deserialize, Clone, Debug)]
+pub struct PuzzleLevel {
+    pub name: String,
+    pub goal: PuzzleGoal,
+    #[serde(default)]
+    pub balls: Vec<PlacedBall>,
+    /// Tier names of the balls the player gets, in drop order
+    pub drops: Vec<String>,
+    /// Most drops used for three stars and for two
+    pub stars: (usize, usize),
+}
+
+impl PuzzleLevel {
+    /// Stars for finishing the level with `drops_used` drops.
+    pub fn stars_for(&self, drops_used: usize) -> u32 {
+        let (three, two) = self.stars;
+        if drops_used <= three {
+            3
+        } else if drops_used <= two {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// The first tier name in the level that `chain` doesn't have.
+    pub fn unknown_tier(&self, chain: &BallChain) -> Option<&str> {
+        let goal = std::iter::once(self.goal.tier());
+        let balls = self.balls.iter().map(|ball| ball.tier.as_str());
+        let drops = self.drops.iter().map(String::as_str);
+        goal.chain(balls).chain(drops).find(|name| chain.find(name).is_none())
+    }
+}
+
+/// Every puzzle level, in level select order. Lives both as an asset (so it
+/// can be hot-reloaded) and as the resource the mode reads from.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+pub struct PuzzleLevels {
+    pub levels: Vec<PuzzleLevel>,
+}
+
+impl Default for PuzzleLevels {
+    fn default() -> Self {
+        Self::built_in()
+    }
+}
+
+impl RonAsset for PuzzleLevels {
+    const PATH: &'static str = "levels.puzzles.ron";
+    const EXTENSIONS: &'static [&'static str] = &["puzzles.ron"];
+    const BUILT_IN: &'static str = include_str!("../assets/levels.puzzles.ron");
+    const NAME: &'static str = "puzzle levels";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.levels.is_empty() {
+            return Err("no levels".to_string());
+        }
+        for level in &self.levels {
+            if level.drops.is_empty() {
+                return Err(format!("level {} has no drops", level.name));
+            }
+            if level.stars.0 > level.stars.1 {
+                return Err(format!("level {} asks for fewer drops for two stars than for three", level.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PuzzleLevels {
+    // Tier names can only be checked against the chain once both are loaded
+    fn check_tiers(&self, chain: &BallChain) -> Result<(), String> {
+        for level in &self.levels {
+            if let Some(name) = level.unknown_tier(chain) {
+                return Err(format!("level {} names unknown tier {name}", level.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The puzzle being played, or picked from the level select page.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct PuzzleRun {
+    /// Index into `PuzzleLevels`
+    pub level: usize,
+    /// Set for the length of a Puzzle game
+    pub active: bool,
+    pub drops_used: usize,
+    pub drops_total: usize,
+    /// Earned when the goal is reached
+    pub stars: u32,
+    settle: f32,
+}
+
+/// Provides the `PuzzleLevels` resource, loaded from
+/// `assets/levels.puzzles.ron` like the ball chain, and plays the level in
+/// `PuzzleRun` whenever a Puzzle game starts.
+pub(crate) struct PuzzlePlugin;
+
+impl Plugin for PuzzlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<PuzzleLevels>::without_applying())
+            .init_resource::<PuzzleRun>()
+            .add_systems(Update, (
+                start_puzzle.after(restart_game),
+                track_puzzle.run_if(in_state(GameState::Playing)),
+            ).chain());
+
+        if app.world().contains_resource::<AssetServer>() {
+            app.add_systems(Update, apply_puzzle_levels);
+        }
+    }
+}
+
+#[derive(Component)]
+struct PuzzleHud;
+
+/// The goal and balls left during a puzzle, and the stars at the end.
+pub(crate) struct PuzzleHudPlugin;
+
+impl Plugin for PuzzleHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_puzzle_hud)
+            .add_systems(Update, update_puzzle_hud)
+            .add_systems(OnEnter(GameState::GameOver), setup_puzzle_results::<GameOverText>.run_if(is_puzzle))
+            .add_systems(OnEnter(GameState::Win), setup_puzzle_results::<WinText>.run_if(is_puzzle));
+    }
+}
+
+pub(crate) fn is_puzzle(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Puzzle
+}
+
+// Re-checked whenever the chain changes too, so levels rejected for naming
+// tiers the old chain didn't have are picked up once a chain that has them
+// loads. start_puzzle refuses levels that still don't fit.
+fn apply_puzzle_levels(
+    mut events: EventReader<AssetEvent<PuzzleLevels>>,
+    handle: Res<RonAssetHandle<PuzzleLevels>>,
+    assets: Res<Assets<PuzzleLevels>>,
+    chain: Res<BallChain>,
+    mut active_levels: ResMut<PuzzleLevels>,
+) {
+    let file_changed = events.read().filter(|event| handle.changed_by(event)).count() > 0;
+    if !(file_changed || chain.is_changed()) {
+        return;
+    }
+    let Some(levels) = assets.get(&handle.0) else {
+        return;
+    };
+    match levels.check_tiers(&chain) {
+        Ok(()) => {
+            info!("Loaded {} puzzle levels", levels.levels.len());
+            *active_levels = levels.clone();
+        }
+        Err(err) => error!("Keeping the current puzzle levels: {err}"),
+    }
+}
+
+// restart_game has emptied the container and rolled a random queue; swap in
+// the level's board and balls
+#[allow(clippy::too_many_arguments)]
+fn start_puzzle(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+    mut started_events: EventReader<GameStarted>,
+    mode: Res<GameMode>,
+    levels: Res<PuzzleLevels>,
+    chain: Res<BallChain>,
+    settings: Res<Settings>,
+    mut rng: ResMut<GameRng>,
+    mut queue: ResMut<DropQueue>,
+    mut run: ResMut<PuzzleRun>,
+    mut preview_query: Query<&mut BallPreview>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if started_events.read().count() == 0 {
+        return;
+    }
+
+    *run = PuzzleRun {
+        level: run.level,
+        ..default()
+    };
+    if *mode != GameMode::Puzzle {
+        return;
+    }
+    run.level = run.level.min(levels.levels.len() - 1);
+    let level = &levels.levels[run.level];
+    if let Some(name) = level.unknown_tier(&chain) {
+        error!("Can't play puzzle {}: unknown tier {name}", level.name);
+        next_state.set(GameState::MainMenu);
+        return;
+    }
+
+    queue.set_fixed(level.drops.iter().filter_map(|name| chain.find(name)));
+    run.drops_total = queue.remaining().unwrap_or_default();
+    run.active = true;
+    if let Ok(mut preview) = preview_query.get_single_mut() {
+        preview.next_size = queue.next(&mut rng.queue, &chain, settings.next_queue_length);
+    }
+
+    for ball in &level.balls {
+        if let Some(variant) = chain.find(&ball.tier) {
+            let position = Vec3::new(ball.x, ball.y, 0.0);
+            spawn_ball_at(&mut commands, asset_server.as_deref(), variant, position, &settings, &mut rng, &chain);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn track_puzzle(
+    time: Res<Time>,
+    levels: Res<PuzzleLevels>,
+    chain: Res<BallChain>,
+    queue: Res<DropQueue>,
+    score: Res<Score>,
+    mut run: ResMut<PuzzleRun>,
+    mut stats: ResMut<Stats>,
+    balls: Query<&Ball>,
+    preview_query: Query<&BallPreview>,
+    mut dropped_events: EventReader<BallDropped>,
+    mut merged_events: EventReader<BallsMerged>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut game_ended_events: EventWriter<GameEnded>,
+) {
+    let dropped = dropped_events.read().count();
+    let merged: Vec<BallVariant> = merged_events.read().map(|merged| merged.into).collect();
+    if !run.active {
+        return;
+    }
+    let Some(level) = levels.levels.get(run.level) else {
+        return;
+    };
+    run.drops_used += dropped;
+
+    let reached = match &level.goal {
+        PuzzleGoal::Create(name) => chain
+            .find(name)
+            .is_some_and(|target| merged.contains(&target)),
+        PuzzleGoal::Clear(name) => chain
+            .find(name)
+            .is_some_and(|target| balls.iter().all(|ball| ball.variant != target)),
+    };
+    if reached {
+        run.active = false;
+        run.stars = level.stars_for(run.drops_used);
+        stats.record_stars(&level.name, run.stars);
+        next_state.set(GameState::Win);
+        game_ended_events.send(GameEnded {
+            outcome: GameOutcome::Won,
+            score: score.current,
+        });
+        return;
+    }
+
+    // Out of balls: lost once the last merges have played out
+    let out_of_balls = queue.remaining() == Some(0)
+        && preview_query.get_single().is_ok_and(|preview| preview.next_size.is_none());
+    if !out_of_balls || !merged.is_empty() || dropped > 0 {
+        run.settle = 0.0;
+        return;
+    }
+    run.settle += time.delta_seconds();
+    if run.settle >= SETTLE_TIME {
+        run.active = false;
+        next_state.set(GameState::GameOver);
+        game_ended_events.send(GameEnded {
+            outcome: GameOutcome::Lost,
+            score: score.current,
+        });
+    }
+}
+
+fn setup_puzzle_hud(mut commands: Commands) {
+    spawn_top_hud(&mut commands, 22.0, PuzzleHud);
+}
+
+fn update_puzzle_hud(
+    run: Res<PuzzleRun>,
+    levels: Res<PuzzleLevels>,
+    state: Res<State<GameState>>,
+    mut hud: Query<(&mut Text, &mut Visibility), With<PuzzleHud>>,
+) {
+    if !(run.is_changed() || state.is_changed()) {
+        return;
+    }
+    let level = levels.levels.get(run.level);
+    let visible = run.active && matches!(state.get(), GameState::Playing | GameState::Paused);
+    for (mut text, mut visibility) in &mut hud {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+        if let Some(level) = level {
+            text.sections[0].value = format!(
+                "{}: {}\nBalls left: {}",
+                level.name,
+                level.goal.describe(run.drops_total),
+                run.drops_total.saturating_sub(run.drops_used),
+            );
+        }
+    }
+}
+
+fn setup_puzzle_results<Overlay: Component + Default>(
+    mut commands: Commands,
+    run: Res<PuzzleRun>,
+    levels: Res<PuzzleLevels>,
+) {
+    let Some(level) = levels.levels.get(run.level) else {
+        return;
+    };
+    let value = if run.stars > 0 {
+        format!("{}: {} of 3 stars in {} drops", level.name, run.stars, run.drops_used)
+    } else {
+        format!("{}: {}", level.name, level.goal.describe(run.drops_total))
+    };
+    spawn_results_line(&mut commands, value, Overlay::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(drops: &[&str], stars: (usize, usize)) -> PuzzleLevel {
+        PuzzleLevel {
+            name: "Test".to_string(),
+            goal: PuzzleGoal::Clear("Sad".to_string()),
+            balls: Vec::new(),
+            drops: drops.iter().map(|name| name.to_string()).collect(),
+            stars,
+        }
+    }
+
+    #[test]
+    fn built_in_levels_are_valid_and_fit_the_built_in_chain() {
+        let levels = PuzzleLevels::default();
+        assert_eq!(levels.validate(), Ok(()));
+        assert_eq!(levels.check_tiers(&BallChain::default()), Ok(()));
+    }
+
+    #[test]
+    fn stars_follow_drops_used() {
+        let level = level(&["Sad"; 6], (3, 5));
+        assert_eq!(level.stars_for(2), 3);
+        assert_eq!(level.stars_for(3), 3);
+        assert_eq!(level.stars_for(4), 2);
+        assert_eq!(level.stars_for(5), 2);
+        assert_eq!(level.stars_for(6), 1);
+    }
+
+    #[test]
+    fn tiers_missing_from_the_chain_are_found() {
+        let typo = level(&["Sad", "Angyr"], (1, 2));
+        assert_eq!(typo.unknown_tier(&BallChain::default()), Some("Angyr"));
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+};
+use serde::de::DeserializeOwned;
+use std::{fmt, marker::PhantomData};
+
+/// Game data written as a `.ron` file in `assets/`, which doubles as the
+/// resource gameplay reads from. A copy of the file is compiled in as the
+/// fallback.
+pub(crate) trait RonAsset: Asset + Resource + Clone + Default + DeserializeOwned {
+    /// Where the file lives under `assets/`
+    const PATH: &'static str;
+    const EXTENSIONS: &'static [&'static str];
+    /// The compiled-in copy of the file
+    const BUILT_IN: &'static str;
+    /// What the file holds, for messages
+    const NAME: &'static str;
+
+    /// Catches what parses but can't be played.
+    fn validate(&self) -> Result<(), String>;
+
+    fn built_in() -> Self {
+        ron::de::from_str(Self::BUILT_IN).unwrap_or_else(|err| panic!("built-in {} should parse: {err}", Self::NAME))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum RonAssetError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Invalid(String),
+}
+
+impl fmt::Display for RonAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RonAssetError::Io(err) => write!(f, "could not read file: {err}"),
+            RonAssetError::Ron(err) => write!(f, "could not parse file: {err}"),
+            RonAssetError::Invalid(reason) => write!(f, "invalid file: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RonAssetError {}
+
+impl From<std::io::Error> for RonAssetError {
+    fn from(err: std::io::Error) -> Self {
+        RonAssetError::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for RonAssetError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        RonAssetError::Ron(err)
+    }
+}
+
+struct RonAssetLoader<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for RonAssetLoader<T> {
+    fn default() -> Self {
+        RonAssetLoader(PhantomData)
+    }
+}
+
+impl<T: RonAsset> AssetLoader for RonAssetLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = RonAssetError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<T, RonAssetError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let asset: T = ron::de::from_bytes(&bytes)?;
+        asset.validate().map_err(RonAssetError::Invalid)?;
+        Ok(asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        T::EXTENSIONS
+    }
+}
+
+/// The file behind a `RonAsset` resource.
+#[derive(Resource)]
+pub(crate) struct RonAssetHandle<T: Asset>(pub Handle<T>);
+
+impl<T: Asset> RonAssetHandle<T> {
+    /// Whether `event` is the file finishing loading or being edited.
+    pub(crate) fn changed_by(&self, event: &AssetEvent<T>) -> bool {
+        event.is_loaded_with_dependencies(&self.0) || event.is_modified(&self.0)
+    }
+}
+
+/// Provides `T` as a resource. When an `AssetServer` is present it's loaded
+/// from `T::PATH` and re-applied whenever the file changes (build with the
+/// `hot_reload` feature to watch for edits); otherwise the built-in copy is
+/// used.
+pub(crate) struct RonAssetPlugin<T> {
+    apply: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for RonAssetPlugin<T> {
+    fn default() -> Self {
+        RonAssetPlugin { apply: true, marker: PhantomData }
+    }
+}
+
+impl<T> RonAssetPlugin<T> {
+    /// Loads the file but leaves applying it to the caller, for files that
+    /// need checking against other resources first.
+    pub(crate) fn without_applying() -> Self {
+        RonAssetPlugin { apply: false, marker: PhantomData }
+    }
+}
+
+impl<T: RonAsset> Plugin for RonAssetPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<T>();
+
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<T>()
+                .init_asset_loader::<RonAssetLoader<T>>()
+                .add_systems(PreStartup, load_ron_asset::<T>);
+            if self.apply {
+                app.add_systems(Update, apply_ron_asset::<T>);
+            }
+        }
+    }
+}
+
+fn load_ron_asset<T: RonAsset>(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(RonAssetHandle::<T>(asset_server.load(T::PATH)));
+}
+
+fn apply_ron_asset<T: RonAsset>(
+    mut events: EventReader<AssetEvent<T>>,
+    handle: Res<RonAssetHandle<T>>,
+    assets: Res<Assets<T>>,
+    mut active: ResMut<T>,
+) {
+    for event in events.read() {
+        if handle.changed_by(event) {
+            if let Some(asset) = assets.get(&handle.0) {
+                info!("Loaded {} from {}", T::NAME, T::PATH);
+                *active = asset.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::settings_menu::clamp_settings;
+use crate::{Settings, Storage};
+
+const SETTINGS_KEY: &str = "settings";
+
+// Bump this and add a step to MIGRATIONS whenever a saved field is renamed,
+// removed or changes meaning. New fields just need a sensible default.
+const SETTINGS_VERSION: u32 = 2;
+
+// MIGRATIONS[n] upgrades a version n+1 save to version n+2, in place
+const MIGRATIONS: &[fn(&mut Value)] = &[split_drop_cooldown];
+const _: () = assert!(MIGRATIONS.len() == SETTINGS_VERSION as usize - 1);
+
+#[derive(Serialize, Deserialize)]
+struct SavedSettings {
+    version: u32,
+    settings: Value,
+}
+
+/// Loads `Settings` before anything reads them and saves them after every change.
+pub(crate) struct SavedSettingsPlugin;
+
+impl Plugin for SavedSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, load_settings)
+            .add_systems(Last, save_settings.run_if(resource_changed::<Settings>));
+    }
+}
+
+fn load_settings(storage: Res<Storage>, mut settings: ResMut<Settings>) {
+    let Some(saved) = storage.load::<SavedSettings>(SETTINGS_KEY) else {
+        return;
+    };
+    match migrate(saved) {
+        // Storage can be edited by hand, or by the page in the browser
+        Ok(mut loaded) => {
+            clamp_settings(&mut loaded);
+            *settings = loaded;
+        }
+        Err(err) => warn!("Ignoring saved settings: {err}"),
+    }
+}
+
+fn migrate(saved: SavedSettings) -> Result<Settings, String> {
+    if saved.version == 0 || saved.version > SETTINGS_VERSION {
+        return Err(format!("unknown settings version {}", saved.version));
+    }
+
+    let mut value = saved.settings;
+    for migration in &MIGRATIONS[(saved.version - 1) as usize..] {
+        migration(&mut value);
+    }
+    serde_json::from_value(value).map_err(|err| err.to_string())
+}
+
+// v1 -> v2: one drop_cooldown shared by every mode except Blitz became a
+// cooldown per mode
+fn split_drop_cooldown(value: &mut Value) {
+    let Some(fields) = value.as_object_mut() else {
+        return;
+    };
+    let Some(cooldown) = fields.remove("drop_cooldown") else {
+        return;
+    };
+    for mode in ["classic", "zen", "daily", "puzzle", "survival"] {
+        fields.insert(format!("{mode}_drop_cooldown"), cooldown.clone());
+    }
+}
+
+fn save_settings(storage: Res<Storage>, settings: Res<Settings>) {
+    match serde_json::to_value(&*settings) {
+        Ok(value) => storage.save(SETTINGS_KEY, &SavedSettings {
+            version: SETTINGS_VERSION,
+            settings: value,
+        }),
+        Err(err) => warn!("Couldn't serialize settings: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn version_1_splits_drop_cooldown_per_mode() {
+        let saved = SavedSettings {
+            version: 1,
+            settings: json!({ "drop_cooldown": 0.1, "blitz_drop_cooldown": 0.2 }),
+        };
+        let settings = migrate(saved).unwrap();
+        for cooldown in [
+            settings.classic_drop_cooldown,
+            settings.zen_drop_cooldown,
+            settings.daily_drop_cooldown,
+            settings.puzzle_drop_cooldown,
+            settings.survival_drop_cooldown,
+        ] {
+            assert_eq!(cooldown, 0.1);
+        }
+        assert_eq!(settings.blitz_drop_cooldown, 0.2);
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        for version in [0, SETTINGS_VERSION + 1] {
+            assert!(migrate(SavedSettings { version, settings: json!({}) }).is_err());
+        }
+    }
+}
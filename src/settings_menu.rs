@@ -0,0 +1,610 @@
+use bevy::{input::InputSystem, prelude::*, window::{PrimaryWindow, WindowMode}};
+
+use crate::input_map::BindingDevice;
+use crate::widgets::{spawn_button, spawn_slider, ScrollView, Slider, SliderFormat, BUTTON_COLOR};
+use crate::{Action, Binding, GameState, InputMap, Settings, VisualEffectsLevel};
+
+const SELECTED_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
+
+#[derive(Resource, Default, PartialEq, Clone, Copy)]
+enum SettingsTab {
+    #[default]
+    Audio,
+    Visuals,
+    Physics,
+    Accessibility,
+    // Rebinding the InputMap rather than Settings fields
+    Controls,
+}
+
+impl SettingsTab {
+    const ALL: [SettingsTab; 5] = [
+        SettingsTab::Audio,
+        SettingsTab::Visuals,
+        SettingsTab::Physics,
+        SettingsTab::Accessibility,
+        SettingsTab::Controls,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SettingsTab::Audio => "Audio",
+            SettingsTab::Visuals => "Visuals",
+            SettingsTab::Physics => "Physics",
+            SettingsTab::Accessibility => "Access",
+            SettingsTab::Controls => "Controls",
+        }
+    }
+}
+
+// One editable `Settings` field. Everything goes through f32 so the same
+// slider works for bools (0/1) and counts.
+struct SettingField {
+    tab: SettingsTab,
+    label: &'static str,
+    min: f32,
+    max: f32,
+    step: f32,
+    format: SliderFormat,
+    get: fn(&Settings) -> f32,
+    set: fn(&mut Settings, f32),
+}
+
+/// Pulls every field with a slider back into that slider's range, for
+/// settings that didn't come from the menu.
+pub(crate) fn clamp_settings(settings: &mut Settings) {
+    for field in SETTING_FIELDS {
+        let value = (field.get)(settings);
+        (field.set)(settings, value.clamp(field.min, field.max));
+    }
+}
+
+const SETTING_FIELDS: &[SettingField] = &[
+    // Audio
+    SettingField {
+        tab: SettingsTab::Audio, label: "Sound", min: 0.0, max: 1.0, step: 1.0, format: SliderFormat::OnOff,
+        get: |s| s.sound_enabled as u8 as f32, set: |s, v| s.sound_enabled = v >= 0.5,
+    },
+    SettingField {
+        tab: SettingsTab::Audio, label: "Volume", min: 0.0, max: 1.0, step: 0.05, format: SliderFormat::Percent,
+        get: |s| s.volume, set: |s, v| s.volume = v,
+    },
+    // Visuals
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Glow", min: 0.0, max: 0.2, step: 0.01, format: SliderFormat::Decimal(2),
+        get: |s| s.glow_intensity, set: |s, v| s.glow_intensity = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Glow speed", min: 0.0, max: 2.0, step: 0.1, format: SliderFormat::Decimal(1),
+        get: |s| s.glow_speed, set: |s, v| s.glow_speed = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Pulse", min: 0.0, max: 0.05, step: 0.005, format: SliderFormat::Decimal(3),
+        get: |s| s.pulse_magnitude, set: |s, v| s.pulse_magnitude = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Pulse speed", min: 0.0, max: 2.0, step: 0.1, format: SliderFormat::Decimal(1),
+        get: |s| s.pulse_speed, set: |s, v| s.pulse_speed = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Color speed", min: 0.0, max: 3.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.color_speed, set: |s, v| s.color_speed = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Background strips", min: 1.0, max: 60.0, step: 1.0, format: SliderFormat::Decimal(0),
+        get: |s| s.background_strip_count as f32, set: |s, v| s.background_strip_count = v.round() as i32,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Saturation", min: 0.0, max: 1.5, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.background_saturation, set: |s, v| s.background_saturation = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Brightness", min: 0.0, max: 1.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.background_brightness, set: |s, v| s.background_brightness = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Explosions", min: 0.0, max: 3.0, step: 0.1, format: SliderFormat::Decimal(1),
+        get: |s| s.explosion_intensity, set: |s, v| s.explosion_intensity = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Particle speed", min: 50.0, max: 600.0, step: 10.0, format: SliderFormat::Decimal(0),
+        get: |s| s.explosion_particle_speed, set: |s, v| s.explosion_particle_speed = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Particle size", min: 1.0, max: 12.0, step: 0.5, format: SliderFormat::Decimal(1),
+        get: |s| s.explosion_particle_size, set: |s, v| s.explosion_particle_size = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Particle count", min: 0.0, max: 40.0, step: 1.0, format: SliderFormat::Decimal(0),
+        get: |s| s.explosion_particle_count, set: |s, v| s.explosion_particle_count = v,
+    },
+    SettingField {
+        tab: SettingsTab::Visuals, label: "Particle life", min: 0.1, max: 1.5, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.explosion_particle_lifetime, set: |s, v| s.explosion_particle_lifetime = v,
+    },
+    // Physics
+    SettingField {
+        tab: SettingsTab::Physics, label: "Bounciness", min: 0.0, max: 1.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.ball_bounciness, set: |s, v| s.ball_bounciness = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Friction", min: 0.0, max: 1.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.ball_friction, set: |s, v| s.ball_friction = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Classic cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.classic_drop_cooldown, set: |s, v| s.classic_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Blitz cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.blitz_drop_cooldown, set: |s, v| s.blitz_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Zen cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.zen_drop_cooldown, set: |s, v| s.zen_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Daily cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.daily_drop_cooldown, set: |s, v| s.daily_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Puzzle cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.puzzle_drop_cooldown, set: |s, v| s.puzzle_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Survival cooldown", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.survival_drop_cooldown, set: |s, v| s.survival_drop_cooldown = v,
+    },
+    SettingField {
+        tab: SettingsTab::Physics, label: "Next balls shown", min: 0.0, max: 5.0, step: 1.0, format: SliderFormat::Decimal(0),
+        get: |s| s.next_queue_length as f32, set: |s, v| s.next_queue_length = v.round() as usize,
+    },
+    // Accessibility
+    SettingField {
+        tab: SettingsTab::Accessibility, label: "Screen shake", min: 0.0, max: 5.0, step: 0.1, format: SliderFormat::Decimal(1),
+        get: |s| s.screen_shake_intensity, set: |s, v| s.screen_shake_intensity = v,
+    },
+    SettingField {
+        tab: SettingsTab::Accessibility, label: "Shake decay", min: 0.5, max: 8.0, step: 0.1, format: SliderFormat::Decimal(1),
+        get: |s| s.screen_shake_decay, set: |s, v| s.screen_shake_decay = v,
+    },
+    SettingField {
+        tab: SettingsTab::Accessibility, label: "Background motion", min: 0.0, max: 2.0, step: 0.05, format: SliderFormat::Decimal(2),
+        get: |s| s.background_animation_speed, set: |s, v| s.background_animation_speed = v,
+    },
+    SettingField {
+        tab: SettingsTab::Accessibility, label: "Fullscreen", min: 0.0, max: 1.0, step: 1.0, format: SliderFormat::OnOff,
+        get: |s| s.is_fullscreen as u8 as f32, set: |s, v| s.is_fullscreen = v >= 0.5,
+    },
+];
+
+#[derive(Component)]
+struct SettingsMenu;
+
+// The clipping box the scrolling field list lives in
+#[derive(Component)]
+struct SettingsPanel;
+
+#[derive(Component, PartialEq, Clone, Copy)]
+struct SettingsTabButton(SettingsTab);
+
+#[derive(Component, PartialEq, Clone, Copy)]
+enum PresetButton {
+    Preset(VisualEffectsLevel),
+    Reset,
+}
+
+// Index into SETTING_FIELDS for the field a slider edits
+#[derive(Component)]
+struct SettingSlider(usize);
+
+/// The settings overlay: a tab per group of `Settings` fields, each edited
+/// live with a slider, plus the effect presets and a tab for rebinding controls.
+pub(crate) struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsTab>()
+            .init_resource::<Rebinding>()
+            .add_systems(OnEnter(GameState::Settings), setup_settings_menu)
+            .add_systems(OnExit(GameState::Settings), cleanup_settings_menu)
+            .add_systems(OnExit(GameState::Settings), |mut rebinding: ResMut<Rebinding>| rebinding.0 = None)
+            .add_systems(PreUpdate, capture_rebind.after(InputSystem).run_if(|rebinding: Res<Rebinding>| rebinding.0.is_some()))
+            .add_systems(Update, (
+                (settings_menu_interaction, apply_slider_changes, sync_sliders_from_settings).chain(),
+                update_button_colors,
+                (controls_interaction, update_rebind_labels).chain(),
+                update_settings_title.run_if(resource_changed::<InputMap>),
+            ).run_if(in_state(GameState::Settings)))
+            .add_systems(Update, apply_window_mode.run_if(resource_changed::<Settings>));
+    }
+}
+
+#[derive(Component)]
+struct SettingsTitle;
+
+fn settings_title(map: &InputMap) -> String {
+    format!("Settings Menu\nPress {} to return", map.hint(Action::Pause))
+}
+
+fn setup_settings_menu(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    tab: Res<SettingsTab>,
+    map: Res<InputMap>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(16.0)),
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                ..default()
+            },
+            SettingsMenu,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                SettingsTitle,
+                TextBundle::from_section(
+                    settings_title(&map),
+                    TextStyle {
+                        font_size: 28.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ).with_text_justify(JustifyText::Center),
+            ));
+
+            // Tabs
+            spawn_button_row(parent, |row| {
+                for tab in SettingsTab::ALL {
+                    spawn_button(row, tab.label(), Vec2::new(86.0, 36.0), SettingsTabButton(tab));
+                }
+            });
+
+            // Presets
+            spawn_button_row(parent, |row| {
+                spawn_button(row, "Low", Vec2::new(80.0, 36.0), PresetButton::Preset(VisualEffectsLevel::Low));
+                spawn_button(row, "Normal", Vec2::new(80.0, 36.0), PresetButton::Preset(VisualEffectsLevel::Normal));
+                spawn_button(row, "High", Vec2::new(80.0, 36.0), PresetButton::Preset(VisualEffectsLevel::High));
+                spawn_button(row, "Reset to preset", Vec2::new(150.0, 36.0), PresetButton::Reset);
+            });
+
+            // Fields for the open tab, scrolled with the mouse wheel
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        flex_basis: Val::Px(0.0),
+                        flex_grow: 1.0,
+                        overflow: Overflow::clip_y(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                SettingsPanel,
+            ))
+            .with_children(|panel| spawn_tab_fields(panel, *tab, &settings));
+        });
+}
+
+fn spawn_button_row(parent: &mut ChildBuilder, buttons: impl FnOnce(&mut ChildBuilder)) {
+    parent.spawn(NodeBundle {
+        style: Style {
+            column_gap: Val::Px(6.0),
+            ..default()
+        },
+        ..default()
+    })
+    .with_children(buttons);
+}
+
+fn spawn_tab_fields(panel: &mut ChildBuilder, tab: SettingsTab, settings: &Settings) {
+    panel.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_self: AlignSelf::FlexStart,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ..default()
+        },
+        ScrollView::default(),
+    ))
+    .with_children(|list| {
+        if tab == SettingsTab::Controls {
+            spawn_controls(list);
+            return;
+        }
+        for (index, field) in SETTING_FIELDS.iter().enumerate().filter(|(_, field)| field.tab == tab) {
+            let slider = Slider {
+                value: (field.get)(settings),
+                min: field.min,
+                max: field.max,
+                step: field.step,
+                format: field.format,
+            };
+            spawn_slider(list, field.label, slider, SettingSlider(index));
+        }
+    });
+}
+
+fn settings_menu_interaction(
+    mut commands: Commands,
+    mut settings: ResMut<Settings>,
+    mut tab: ResMut<SettingsTab>,
+    tab_buttons: Query<(&Interaction, &SettingsTabButton), Changed<Interaction>>,
+    preset_buttons: Query<(&Interaction, &PresetButton), Changed<Interaction>>,
+    panels: Query<Entity, With<SettingsPanel>>,
+) {
+    for (interaction, button) in &tab_buttons {
+        if *interaction == Interaction::Pressed && *tab != button.0 {
+            *tab = button.0;
+            for panel in &panels {
+                commands.entity(panel)
+                    .despawn_descendants()
+                    .with_children(|panel| spawn_tab_fields(panel, button.0, &settings));
+            }
+        }
+    }
+
+    for (interaction, button) in &preset_buttons {
+        if *interaction == Interaction::Pressed {
+            match *button {
+                PresetButton::Preset(level) => settings.apply_preset(level),
+                PresetButton::Reset => settings.reset_to_preset(),
+            }
+        }
+    }
+}
+
+// Slider -> Settings. Only writes when the value really moved, so refreshing
+// the sliders from Settings doesn't echo back.
+fn apply_slider_changes(
+    mut settings: ResMut<Settings>,
+    sliders: Query<(&Slider, &SettingSlider), Changed<Slider>>,
+) {
+    for (slider, field) in &sliders {
+        let field = &SETTING_FIELDS[field.0];
+        if (field.get)(&settings) != slider.value {
+            (field.set)(&mut settings, slider.value);
+        }
+    }
+}
+
+// Settings -> Slider, for presets and anything else that edits Settings
+fn sync_sliders_from_settings(
+    settings: Res<Settings>,
+    mut sliders: Query<(&mut Slider, &SettingSlider)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut slider, field) in &mut sliders {
+        let value = (SETTING_FIELDS[field.0].get)(&settings);
+        if slider.value != value {
+            slider.value = value;
+        }
+    }
+}
+
+fn update_button_colors(
+    settings: Res<Settings>,
+    tab: Res<SettingsTab>,
+    mut tab_buttons: Query<(&SettingsTabButton, &mut BackgroundColor), Without<PresetButton>>,
+    mut preset_buttons: Query<(&PresetButton, &mut BackgroundColor), Without<SettingsTabButton>>,
+) {
+    for (button, mut color) in &mut tab_buttons {
+        *color = BackgroundColor(if button.0 == *tab { SELECTED_COLOR } else { BUTTON_COLOR });
+    }
+    for (button, mut color) in &mut preset_buttons {
+        let selected = *button == PresetButton::Preset(settings.visual_effects);
+        *color = BackgroundColor(if selected { SELECTED_COLOR } else { BUTTON_COLOR });
+    }
+}
+
+// Pause can be rebound from the Controls tab while the menu is open
+fn update_settings_title(map: Res<InputMap>, mut titles: Query<&mut Text, With<SettingsTitle>>) {
+    for mut text in &mut titles {
+        text.sections[0].value = settings_title(&map);
+    }
+}
+
+fn apply_window_mode(settings: Res<Settings>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let mode = if settings.is_fullscreen {
+        WindowMode::BorderlessFullscreen
+    } else {
+        WindowMode::Windowed
+    };
+    for mut window in &mut windows {
+        if window.mode != mode {
+            window.mode = mode;
+        }
+    }
+}
+
+fn cleanup_settings_menu(
+    mut commands: Commands,
+    query: Query<Entity, With<SettingsMenu>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// The action and device waiting for a new binding on the controls tab
+#[derive(Resource, Default)]
+struct Rebinding(Option<(Action, BindingDevice)>);
+
+#[derive(Component)]
+struct RebindButton {
+    action: Action,
+    device: BindingDevice,
+}
+
+#[derive(Component)]
+struct ResetControlsButton;
+
+// Rows of rebind buttons for the controls tab
+fn spawn_controls(list: &mut ChildBuilder) {
+    for action in Action::ALL {
+        list.spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(34.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn(TextBundle {
+                text: Text::from_section(action.label(), TextStyle {
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                }),
+                style: Style {
+                    width: Val::Px(110.0),
+                    ..default()
+                },
+                ..default()
+            });
+            spawn_rebind_button(row, action, BindingDevice::Keyboard, 170.0);
+            spawn_rebind_button(row, action, BindingDevice::Gamepad, 140.0);
+        });
+    }
+
+    spawn_button(list, "Reset controls", Vec2::new(200.0, 30.0), ResetControlsButton);
+}
+
+fn spawn_rebind_button(row: &mut ChildBuilder, action: Action, device: BindingDevice, width: f32) {
+    // The label is filled in by update_rebind_labels
+    spawn_button(row, "", Vec2::new(width, 30.0), RebindButton { action, device });
+}
+
+fn controls_interaction(
+    rebind_buttons: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+    reset_buttons: Query<&Interaction, (Changed<Interaction>, With<ResetControlsButton>)>,
+    mut rebinding: ResMut<Rebinding>,
+    mut map: ResMut<InputMap>,
+) {
+    for (interaction, button) in &rebind_buttons {
+        if *interaction == Interaction::Pressed {
+            rebinding.0 = Some((button.action, button.device));
+        }
+    }
+    if reset_buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        *map = InputMap::default();
+        rebinding.0 = None;
+    }
+}
+
+// Runs before gameplay sees input and swallows the key or button it takes, so
+// binding R doesn't also restart the game. Escape cancels.
+fn capture_rebind(
+    mut rebinding: ResMut<Rebinding>,
+    mut map: ResMut<InputMap>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut gamepad_buttons: ResMut<ButtonInput<GamepadButton>>,
+) {
+    let Some((action, device)) = rebinding.0 else {
+        return;
+    };
+
+    let key = keyboard.get_just_pressed().next().copied();
+    if let Some(key) = key {
+        keyboard.reset(key);
+        if key == KeyCode::Escape {
+            rebinding.0 = None;
+        } else if device == BindingDevice::Keyboard {
+            map.rebind(action, Binding::Key(key));
+            rebinding.0 = None;
+        }
+        return;
+    }
+
+    let button = gamepad_buttons.get_just_pressed().next().copied();
+    if let Some(button) = button {
+        gamepad_buttons.reset(button);
+        if device == BindingDevice::Gamepad {
+            map.rebind(action, Binding::Gamepad(button.button_type));
+            rebinding.0 = None;
+        }
+    }
+}
+
+fn update_rebind_labels(
+    map: Res<InputMap>,
+    rebinding: Res<Rebinding>,
+    buttons: Query<(Ref<RebindButton>, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (button, children) in &buttons {
+        if !(map.is_changed() || rebinding.is_changed() || button.is_added()) {
+            continue;
+        }
+        let label = if rebinding.0 == Some((button.action, button.device)) {
+            "Press a button...".to_string()
+        } else {
+            let names: Vec<String> = map
+                .bindings(button.action)
+                .iter()
+                .filter(|binding| binding.device() == button.device)
+                .map(Binding::name)
+                .collect();
+            if names.is_empty() { "-".to_string() } else { names.join(", ") }
+        };
+        if let Some(mut text) = children.first().and_then(|child| texts.get_mut(*child).ok()) {
+            text.sections[0].value = label;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn within_slider_ranges(settings: &Settings) -> bool {
+        let mut clamped = settings.clone();
+        clamp_settings(&mut clamped);
+        clamped == *settings
+    }
+
+    #[test]
+    fn defaults_and_presets_are_within_slider_ranges() {
+        assert!(within_slider_ranges(&Settings::default()));
+        for level in [VisualEffectsLevel::Low, VisualEffectsLevel::Normal, VisualEffectsLevel::High] {
+            let mut settings = Settings::default();
+            settings.apply_preset(level);
+            assert!(within_slider_ranges(&settings), "{level:?} is outside a slider's range");
+        }
+    }
+
+    #[test]
+    fn clamping_pulls_values_into_range() {
+        let mut settings = Settings {
+            next_queue_length: 1_000_000_000,
+            background_strip_count: -5,
+            volume: 3.0,
+            ..default()
+        };
+        clamp_settings(&mut settings);
+        assert_eq!(settings.next_queue_length, 5);
+        assert_eq!(settings.background_strip_count, 1);
+        assert_eq!(settings.volume, 1.0);
+    }
+}
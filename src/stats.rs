@@ -0,0 +1,111 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use serde::{Deserialize, Serialize};
+
+use crate::{Ball, BallChain, GameMode, GameState, Score, Storage};
+
+const STATS_KEY: &str = "stats";
+
+// Zen games never end and other games can be abandoned or closed, so stats
+// are also saved every so often while playing
+const STATS_AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Lifetime player stats, kept in `Storage` between sessions.
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+pub struct Stats {
+    /// Best Classic score
+    pub high_score: u32,
+    /// Best scores in the other modes
+    pub mode_high_scores: HashMap<GameMode, u32>,
+    pub games_played: u32,
+    pub total_merges: u32,
+    /// Name of the biggest tier ever on the board
+    pub highest_variant: Option<String>,
+    pub time_played_secs: f64,
+    /// Longest chain of cascading merges
+    pub best_combo: u32,
+    /// Most stars earned on each puzzle level, by level name
+    pub puzzle_stars: HashMap<String, u32>,
+}
+
+impl Stats {
+    pub fn best_score(&self, mode: GameMode) -> u32 {
+        match mode {
+            GameMode::Classic => self.high_score,
+            mode => self.mode_high_scores.get(&mode).copied().unwrap_or(0),
+        }
+    }
+
+    pub(crate) fn record_score(&mut self, mode: GameMode, score: u32) {
+        let best = match mode {
+            GameMode::Classic => &mut self.high_score,
+            mode => self.mode_high_scores.entry(mode).or_default(),
+        };
+        *best = (*best).max(score);
+    }
+
+    pub(crate) fn record_stars(&mut self, level: &str, stars: u32) {
+        let best = self.puzzle_stars.entry(level.to_string()).or_default();
+        *best = (*best).max(stars);
+    }
+}
+
+/// Loads `Stats` at startup and saves them whenever a game ends, on going back
+/// to the menu, on exit and periodically.
+pub(crate) struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Stats>()
+            .add_systems(Startup, load_stats)
+            .add_systems(Update, (
+                track_highest_variant,
+                track_time_played.run_if(in_state(GameState::Playing)),
+            ))
+            .add_systems(OnEnter(GameState::GameOver), record_game)
+            .add_systems(OnEnter(GameState::Win), record_game)
+            .add_systems(Update, save_stats.run_if(
+                on_timer(STATS_AUTOSAVE_INTERVAL)
+                    .or_else(state_changed::<GameState>.and_then(in_state(GameState::MainMenu))),
+            ))
+            .add_systems(Last, save_stats.run_if(on_event::<AppExit>()));
+    }
+}
+
+fn load_stats(storage: Res<Storage>, mode: Res<GameMode>, mut stats: ResMut<Stats>, mut score: ResMut<Score>) {
+    if let Some(saved) = storage.load::<Stats>(STATS_KEY) {
+        *stats = saved;
+    }
+    score.high_score = score.high_score.max(stats.best_score(*mode));
+}
+
+fn record_game(storage: Res<Storage>, mode: Res<GameMode>, mut stats: ResMut<Stats>, score: Res<Score>) {
+    stats.games_played += 1;
+    stats.record_score(*mode, score.high_score);
+    storage.save(STATS_KEY, &*stats);
+}
+
+// Also keeps the best score of a game that never ended, like Zen's
+fn save_stats(storage: Res<Storage>, mode: Res<GameMode>, mut stats: ResMut<Stats>, score: Res<Score>) {
+    stats.record_score(*mode, score.high_score);
+    storage.save(STATS_KEY, &*stats);
+}
+
+fn track_highest_variant(
+    chain: Res<BallChain>,
+    new_balls: Query<&Ball, Added<Ball>>,
+    mut stats: ResMut<Stats>,
+) {
+    let highest = stats.highest_variant.as_deref().and_then(|name| chain.find(name));
+    if let Some(biggest) = new_balls.iter().map(|ball| ball.variant).max_by_key(|variant| variant.0) {
+        if highest.is_none_or(|highest| biggest.0 > highest.0) {
+            stats.highest_variant = Some(chain.tier(biggest).name.clone());
+        }
+    }
+}
+
+fn track_time_played(time: Res<Time>, mut stats: ResMut<Stats>) {
+    stats.time_played_secs += time.delta_seconds_f64();
+}
@@ -0,0 +1,143 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Mutex;
+
+/// Somewhere to keep small text blobs between sessions.
+pub trait StorageBackend: Send + Sync + 'static {
+    fn load(&self, key: &str) -> Option<String>;
+    fn save(&self, key: &str, value: &str) -> Result<(), String>;
+}
+
+/// The storage the game persists to. Defaults to a file under the user's
+/// config dir on native builds and `localStorage` on the web; insert your own
+/// before adding `BallDropPlugin` to override it.
+#[derive(Resource)]
+pub struct Storage(Box<dyn StorageBackend>);
+
+impl Storage {
+    pub fn new(backend: impl StorageBackend) -> Self {
+        Self(Box::new(backend))
+    }
+
+    /// Keeps everything in memory, so nothing outlives the app.
+    pub fn in_memory() -> Self {
+        Self::new(MemoryStorage::default())
+    }
+
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.0.load(key)?;
+        match serde_json::from_str(&value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                warn!("Ignoring unreadable saved {key}: {err}");
+                None
+            }
+        }
+    }
+
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) {
+        let result = serde_json::to_string(value)
+            .map_err(|err| err.to_string())
+            .and_then(|value| self.0.save(key, &value));
+        if let Err(err) = result {
+            warn!("Couldn't save {key}: {err}");
+        }
+    }
+}
+
+impl Default for Storage {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        match FileStorage::in_config_dir() {
+            Some(storage) => Self::new(storage),
+            None => {
+                warn!("No config directory found; progress won't be saved");
+                Self::in_memory()
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self::new(LocalStorage)
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryStorage(Mutex<HashMap<String, String>>);
+
+impl StorageBackend for MemoryStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        self.0.lock().ok()?.get(key).cloned()
+    }
+
+    fn save(&self, key: &str, value: &str) -> Result<(), String> {
+        self.0
+            .lock()
+            .map_err(|err| err.to_string())?
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+/// One JSON file per key in a directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `<config dir>/ball_drop`, e.g. `~/.config/ball_drop` on Linux.
+    pub fn in_config_dir() -> Option<Self> {
+        dirs::config_dir().map(|dir| Self::new(dir.join("ball_drop")))
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(key)).ok()
+    }
+
+    fn save(&self, key: &str, value: &str) -> Result<(), String> {
+        std::fs::create_dir_all(&self.dir).map_err(|err| err.to_string())?;
+        std::fs::write(self.path(key), value).map_err(|err| err.to_string())
+    }
+}
+
+/// The browser's `localStorage`, with keys prefixed by `ball_drop.`.
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    fn storage() -> Result<web_sys::Storage, String> {
+        web_sys::window()
+            .ok_or("no window")?
+            .local_storage()
+            .map_err(|err| format!("{err:?}"))?
+            .ok_or_else(|| "localStorage is unavailable".to_string())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        Self::storage().ok()?.get_item(&format!("ball_drop.{key}")).ok()?
+    }
+
+    fn save(&self, key: &str, value: &str) -> Result<(), String> {
+        Self::storage()?
+            .set_item(&format!("ball_drop.{key}"), value)
+            .map_err(|err| format!("{err:?}"))
+    }
+}
@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::ron_asset::{RonAsset, RonAssetPlugin};
+use crate::widgets::{spawn_results_line, spawn_top_hud};
+use crate::{
+    apply_merges, restart_game, GameMode, GameOverText, GameStarted, GameState, Ground, Score,
+    GROUND_Y,
+};
+
+/// How fast Survival's floor climbs, as written in a `.curve.ron` file.
+/// Lives both as an asset (so it can be hot-reloaded) and as a resource.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+pub struct SurvivalCurve {
+    /// (seconds survived, pixels per second) in time order. Linear between
+    /// points and flat after the last one
+    pub floor_speed: Vec<(f32, f32)>,
+    /// How far above its usual height the floor can climb
+    pub max_rise: f32,
+}
+
+impl Default for SurvivalCurve {
+    fn default() -> Self {
+        Self::built_in()
+    }
+}
+
+impl SurvivalCurve {
+    /// Floor speed in pixels per second after `elapsed` seconds.
+    pub fn speed_at(&self, elapsed: f32) -> f32 {
+        let Some(&(first_time, first_speed)) = self.floor_speed.first() else {
+            return 0.0;
+        };
+        if elapsed <= first_time {
+            return first_speed;
+        }
+        for pair in self.floor_speed.windows(2) {
+            let ((from_time, from_speed), (to_time, to_speed)) = (pair[0], pair[1]);
+            if elapsed <= to_time {
+                let t = (elapsed - from_time) / (to_time - from_time);
+                return from_speed + (to_speed - from_speed) * t;
+            }
+        }
+        self.floor_speed.last().map_or(0.0, |&(_, speed)| speed)
+    }
+}
+
+impl RonAsset for SurvivalCurve {
+    const PATH: &'static str = "survival.curve.ron";
+    const EXTENSIONS: &'static [&'static str] = &["curve.ron"];
+    const BUILT_IN: &'static str = include_str!("../assets/survival.curve.ron");
+    const NAME: &'static str = "survival curve";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.floor_speed.is_empty() {
+            return Err("no floor_speed points".to_string());
+        }
+        if self.floor_speed.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            return Err("floor_speed times must increase".to_string());
+        }
+        if self.floor_speed.iter().any(|&(_, speed)| speed < 0.0) {
+            return Err("floor_speed has a negative speed".to_string());
+        }
+        if self.max_rise < 0.0 {
+            return Err("max_rise is negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Time survived and how far the floor has climbed. Idle in the other modes.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct SurvivalClock {
+    pub elapsed: f32,
+    /// Pixels above the usual floor height
+    pub rise: f32,
+    active: bool,
+}
+
+/// Raises the floor along the `SurvivalCurve` from
+/// `assets/survival.curve.ron`, and scores Survival games by time survived.
+pub(crate) struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<SurvivalCurve>::default())
+            .init_resource::<SurvivalClock>()
+            .add_systems(Update, start_survival.after(restart_game))
+            .add_systems(Update, tick_survival.after(apply_merges).run_if(in_state(GameState::Playing)));
+    }
+}
+
+#[derive(Component)]
+struct SurvivalHud;
+
+/// The time survived during the game and at the end.
+pub(crate) struct SurvivalHudPlugin;
+
+impl Plugin for SurvivalHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_survival_hud)
+            .add_systems(Update, update_survival_hud)
+            .add_systems(OnEnter(GameState::GameOver), setup_survival_results.run_if(is_survival));
+    }
+}
+
+pub(crate) fn is_survival(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Survival
+}
+
+fn format_time(seconds: f32) -> String {
+    let seconds = seconds as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+// Every game starts on the usual floor, whatever the last one left it at
+fn start_survival(
+    mode: Res<GameMode>,
+    mut clock: ResMut<SurvivalClock>,
+    mut started_events: EventReader<GameStarted>,
+    mut ground: Query<&mut Transform, With<Ground>>,
+) {
+    if started_events.read().count() == 0 {
+        return;
+    }
+    *clock = SurvivalClock {
+        active: *mode == GameMode::Survival,
+        ..default()
+    };
+    for mut transform in &mut ground {
+        transform.translation.y = GROUND_Y;
+    }
+}
+
+// Merges still clear room but score nothing here (see apply_merges); the
+// clock is the score
+fn tick_survival(
+    time: Res<Time>,
+    curve: Res<SurvivalCurve>,
+    mut clock: ResMut<SurvivalClock>,
+    mut score: ResMut<Score>,
+    mut ground: Query<&mut Transform, With<Ground>>,
+) {
+    if !clock.active {
+        return;
+    }
+    clock.elapsed += time.delta_seconds();
+    let speed = curve.speed_at(clock.elapsed);
+    clock.rise = (clock.rise + speed * time.delta_seconds()).min(curve.max_rise);
+    for mut transform in &mut ground {
+        transform.translation.y = GROUND_Y + clock.rise;
+    }
+
+    score.current = clock.elapsed as u32;
+    score.high_score = score.high_score.max(score.current);
+}
+
+fn setup_survival_hud(mut commands: Commands) {
+    spawn_top_hud(&mut commands, 34.0, SurvivalHud);
+}
+
+fn update_survival_hud(
+    clock: Res<SurvivalClock>,
+    state: Res<State<GameState>>,
+    mut hud: Query<(&mut Text, &mut Visibility), With<SurvivalHud>>,
+) {
+    if !(clock.is_changed() || state.is_changed()) {
+        return;
+    }
+    let visible = clock.active && matches!(state.get(), GameState::Playing | GameState::Paused);
+    for (mut text, mut visibility) in &mut hud {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+        text.sections[0].value = format_time(clock.elapsed);
+    }
+}
+
+fn setup_survival_results(mut commands: Commands, clock: Res<SurvivalClock>, score: Res<Score>) {
+    let value = format!(
+        "Survived {}\nBest: {}",
+        format_time(clock.elapsed),
+        format_time(score.high_score as f32),
+    );
+    spawn_results_line(&mut commands, value, GameOverText);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_curve_is_valid() {
+        assert_eq!(SurvivalCurve::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn floor_speed_follows_the_curve() {
+        let curve = SurvivalCurve {
+            floor_speed: vec![(10.0, 5.0), (30.0, 15.0)],
+            max_rise: 100.0,
+        };
+        assert_eq!(curve.speed_at(0.0), 5.0);
+        assert_eq!(curve.speed_at(20.0), 10.0);
+        assert_eq!(curve.speed_at(30.0), 15.0);
+        assert_eq!(curve.speed_at(90.0), 15.0);
+
+        let flat = SurvivalCurve { floor_speed: Vec::new(), max_rise: 0.0 };
+        assert_eq!(flat.speed_at(5.0), 0.0);
+    }
+}
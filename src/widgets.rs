@@ -0,0 +1,330 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
+
+const TRACK_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
+const FILL_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
+pub(crate) const BUTTON_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+pub(crate) const BUTTON_HOVER_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+// The main menu and pause menu buttons
+pub(crate) const MENU_BUTTON_SIZE: Vec2 = Vec2::new(200.0, 44.0);
+
+/// A button of `size` pixels with a centred label half as tall as the button.
+pub(crate) fn spawn_button(parent: &mut ChildBuilder, label: &str, size: Vec2, marker: impl Bundle) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width: Val::Px(size.x),
+                height: Val::Px(size.y),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: BackgroundColor(BUTTON_COLOR),
+            ..default()
+        },
+        marker,
+    ))
+    .with_children(|button| {
+        button.spawn(TextBundle::from_section(label, TextStyle {
+            font_size: size.y / 2.0,
+            color: Color::WHITE,
+            ..default()
+        }));
+    });
+}
+
+/// The colour of a button that lights up under the cursor.
+pub(crate) fn hover_color(interaction: &Interaction) -> BackgroundColor {
+    BackgroundColor(match interaction {
+        Interaction::None => BUTTON_COLOR,
+        _ => BUTTON_HOVER_COLOR,
+    })
+}
+
+/// How a slider shows its value.
+/// An empty line of text across the top of the screen, for a mode's HUD to
+/// fill in.
+pub(crate) fn spawn_top_hud(commands: &mut Commands, font_size: f32, marker: impl Bundle) {
+    commands.spawn((
+        marker,
+        TextBundle::from_section("", TextStyle {
+            font_size,
+            color: Color::WHITE,
+            ..default()
+        })
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            width: Val::Percent(100.0),
+            ..default()
+        })
+        .with_text_justify(JustifyText::Center),
+    ));
+}
+
+/// A line of text near the bottom of the game over or win screen. `overlay`
+/// should be that screen's marker, so the line is cleaned up with it.
+pub(crate) fn spawn_results_line(commands: &mut Commands, value: String, overlay: impl Bundle) {
+    commands.spawn((
+        overlay,
+        TextBundle::from_section(value, TextStyle {
+            font_size: 24.0,
+            color: Color::WHITE,
+            ..default()
+        })
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(40.0),
+            width: Val::Percent(100.0),
+            ..default()
+        }),
+    ));
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum SliderFormat {
+    Decimal(usize),
+    Percent,
+    OnOff,
+}
+
+/// A value between `min` and `max`, edited by dragging the track or with the
+/// -/+ stepper buttons. The component is `Changed` whenever the value moves.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub format: SliderFormat,
+}
+
+impl Slider {
+    fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    // Snaps to the step and clamps to the range
+    fn snapped(&self, value: f32) -> f32 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max)
+    }
+
+    fn label(&self) -> String {
+        match self.format {
+            SliderFormat::Decimal(places) => format!("{:.*}", places, self.value),
+            SliderFormat::Percent => format!("{:.0}%", self.value * 100.0),
+            SliderFormat::OnOff => if self.value >= 0.5 { "ON" } else { "OFF" }.to_string(),
+        }
+    }
+}
+
+// The children of a slider point back at the entity holding `Slider`
+#[derive(Component)]
+struct SliderTrack(Entity);
+
+#[derive(Component)]
+struct SliderFill(Entity);
+
+#[derive(Component)]
+struct SliderValueText(Entity);
+
+#[derive(Component)]
+struct SliderStep {
+    slider: Entity,
+    direction: f32,
+}
+
+/// Content that scrolls with the mouse wheel inside a clipping parent.
+#[derive(Component, Default)]
+pub(crate) struct ScrollView {
+    position: f32,
+}
+
+pub(crate) struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            (drag_sliders, step_sliders, draw_sliders).chain(),
+            scroll_views,
+        ));
+    }
+}
+
+/// Spawns a row with a label, stepper buttons around a draggable track, and the
+/// current value. Returns the entity holding the `Slider`.
+pub(crate) fn spawn_slider(
+    parent: &mut ChildBuilder,
+    label: &str,
+    slider: Slider,
+    extra: impl Bundle,
+) -> Entity {
+    let mut row = parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(32.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        },
+        slider,
+        extra,
+    ));
+    let root = row.id();
+
+    row.with_children(|row| {
+        row.spawn(TextBundle {
+            text: Text::from_section(label, TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            }),
+            style: Style {
+                width: Val::Px(150.0),
+                ..default()
+            },
+            ..default()
+        });
+
+        spawn_button(row, "-", Vec2::splat(28.0), SliderStep { slider: root, direction: -1.0 });
+
+        row.spawn((
+            ButtonBundle {
+                style: Style {
+                    flex_grow: 1.0,
+                    height: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(TRACK_COLOR),
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            SliderTrack(root),
+        ))
+        .with_children(|track| {
+            track.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(slider.fraction() * 100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(FILL_COLOR),
+                    ..default()
+                },
+                SliderFill(root),
+            ));
+        });
+
+        spawn_button(row, "+", Vec2::splat(28.0), SliderStep { slider: root, direction: 1.0 });
+
+        row.spawn((
+            TextBundle {
+                text: Text::from_section(slider.label(), TextStyle {
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                }),
+                style: Style {
+                    width: Val::Px(48.0),
+                    ..default()
+                },
+                ..default()
+            },
+            SliderValueText(root),
+        ));
+    });
+
+    root
+}
+
+fn drag_sliders(
+    tracks: Query<(&Interaction, &RelativeCursorPosition, &SliderTrack)>,
+    mut sliders: Query<&mut Slider>,
+) {
+    for (interaction, cursor, track) in &tracks {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let (Some(cursor), Ok(mut slider)) = (cursor.normalized, sliders.get_mut(track.0)) else {
+            continue;
+        };
+        let value = slider.snapped(slider.min + cursor.x.clamp(0.0, 1.0) * (slider.max - slider.min));
+        if value != slider.value {
+            slider.value = value;
+        }
+    }
+}
+
+fn step_sliders(
+    buttons: Query<(&Interaction, &SliderStep), Changed<Interaction>>,
+    mut sliders: Query<&mut Slider>,
+) {
+    for (interaction, step) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut slider) = sliders.get_mut(step.slider) {
+            let value = slider.snapped(slider.value + step.direction * slider.step);
+            if value != slider.value {
+                slider.value = value;
+            }
+        }
+    }
+}
+
+fn draw_sliders(
+    sliders: Query<Ref<Slider>>,
+    mut fills: Query<(&SliderFill, &mut Style)>,
+    mut texts: Query<(&SliderValueText, &mut Text)>,
+) {
+    for (fill, mut style) in &mut fills {
+        if let Ok(slider) = sliders.get(fill.0) {
+            if slider.is_changed() {
+                style.width = Val::Percent(slider.fraction() * 100.0);
+            }
+        }
+    }
+    for (value_text, mut text) in &mut texts {
+        if let Ok(slider) = sliders.get(value_text.0) {
+            if slider.is_changed() {
+                text.sections[0].value = slider.label();
+            }
+        }
+    }
+}
+
+fn scroll_views(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut views: Query<(&mut ScrollView, &mut Style, &Parent, &Node)>,
+    nodes: Query<&Node>,
+) {
+    for event in wheel_events.read() {
+        let dy = match event.unit {
+            MouseScrollUnit::Line => event.y * 24.0,
+            MouseScrollUnit::Pixel => event.y,
+        };
+        for (mut view, mut style, parent, node) in &mut views {
+            let visible_height = nodes.get(parent.get()).map(|parent| parent.size().y).unwrap_or(0.0);
+            let max_scroll = (node.size().y - visible_height).max(0.0);
+            view.position = (view.position + dy).clamp(-max_scroll, 0.0);
+            style.top = Val::Px(view.position);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::{Ball, BallChain, BallVariant, DangerZone};
+
+/// A ball popped away to make room in Zen mode. It scores nothing.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BallCleared {
+    pub variant: BallVariant,
+    pub position: Vec3,
+}
+
+// Sent by check_danger_zone in Zen mode where other modes would end the game
+#[derive(Event, Default)]
+pub(crate) struct ClearTopLayer;
+
+/// Zen mode's replacement for losing: when the danger zone timer runs out,
+/// every ball reaching into the zone is popped.
+pub(crate) struct ZenPlugin;
+
+impl Plugin for ZenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BallCleared>()
+            .add_event::<ClearTopLayer>()
+            .add_systems(Update, clear_top_layer);
+    }
+}
+
+fn clear_top_layer(
+    mut commands: Commands,
+    mut clear_events: EventReader<ClearTopLayer>,
+    mut cleared_events: EventWriter<BallCleared>,
+    danger_zone: Res<DangerZone>,
+    chain: Res<BallChain>,
+    balls: Query<(Entity, &Ball, &Transform)>,
+) {
+    if clear_events.read().count() == 0 {
+        return;
+    }
+    for (entity, ball, transform) in &balls {
+        let top = transform.translation.y + ball.variant.size(&chain) / 2.0;
+        if top > danger_zone.height {
+            commands.entity(entity).despawn();
+            cleared_events.send(BallCleared {
+                variant: ball.variant,
+                position: transform.translation,
+            });
+        }
+    }
+}
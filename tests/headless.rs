@@ -0,0 +1,69 @@
+use ball_drop_rust::*;
+use bevy::prelude::*;
+
+const SEED: u64 = 42;
+
+// headless_app has already added the plugin, so the fixed seed takes over
+// from the next restart
+fn seeded_app(seed: u64) -> App {
+    let mut app = headless_app();
+    app.insert_resource(GameRng::new(seed));
+    app.world_mut().send_event(RestartGame);
+    app.update();
+    app
+}
+
+fn state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+fn score(app: &App) -> u32 {
+    app.world().resource::<Score>().current
+}
+
+// Drops a ball every `gap` frames at positions sweeping across the container
+fn play(app: &mut App, drops: usize, gap: usize) {
+    for drop in 0..drops {
+        let x = (drop as f32 * 73.0) % 400.0 - 200.0;
+        app.world_mut().send_event(DropBall { x });
+        for _ in 0..gap {
+            app.update();
+        }
+    }
+}
+
+#[test]
+fn drops_merge_and_score() {
+    let mut app = seeded_app(SEED);
+    play(&mut app, 20, 45);
+
+    assert!(score(&app) > 0, "twenty drops should merge at least once");
+    assert_eq!(app.world().resource::<Score>().high_score, score(&app));
+    assert_eq!(state(&app), GameState::Playing);
+}
+
+#[test]
+fn same_seed_plays_out_the_same() {
+    let mut first = seeded_app(SEED);
+    let mut second = seeded_app(SEED);
+    play(&mut first, 15, 40);
+    play(&mut second, 15, 40);
+
+    assert_eq!(score(&first), score(&second));
+    assert_eq!(state(&first), state(&second));
+    assert_eq!(first.world().resource::<GameRng>().seed(), SEED);
+}
+
+#[test]
+fn overfilling_the_container_ends_the_game() {
+    let mut app = seeded_app(SEED);
+    for _ in 0..400 {
+        if state(&app) == GameState::GameOver {
+            break;
+        }
+        play(&mut app, 1, 8);
+    }
+
+    assert_eq!(state(&app), GameState::GameOver);
+    assert_eq!(app.world().resource::<Stats>().games_played, 1);
+}